@@ -0,0 +1,143 @@
+// Day 1: Historian Hysteria
+// solution
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+use util::{ParseError, Solution};
+
+pub mod metrics;
+pub mod number;
+pub mod schema;
+pub mod stream;
+
+pub use metrics::Metric;
+pub use number::{Number, Overflow};
+pub use schema::{Delimiter, Schema};
+
+pub struct HistorianHysteria;
+
+const DAY: u8 = HistorianHysteria::DAY;
+
+impl Solution for HistorianHysteria {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = LocationLists;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_lists(input)
+    }
+
+    fn part1(lists: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(solution_part1(lists))
+    }
+
+    fn part2(lists: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(solution_part2(lists))
+    }
+}
+
+#[derive(Debug)]
+pub struct LocationLists {
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+}
+
+/// Reads one pair of location IDs per line, pointing at the first token
+/// that is missing or not a number.
+pub fn parse_lists(input: &str) -> Result<LocationLists, ParseError> {
+    let (left, right) = Schema::default().read(input)?;
+    Ok(LocationLists { left, right })
+}
+
+pub fn solution_part1(lists: &LocationLists) -> u64 {
+    let mut first_list_sorted = lists.left.clone();
+    let mut second_list_sorted = lists.right.clone();
+    first_list_sorted.sort();
+    second_list_sorted.sort();
+
+    first_list_sorted
+        .iter()
+        .zip(second_list_sorted.iter())
+        .map(|(a, b)| u64::from(u32::abs_diff(*a, *b)))
+        .sum()
+}
+
+pub fn solution_part2(lists: &LocationLists) -> u64 {
+    let mut right_freq_map = HashMap::new();
+    for num in &lists.right {
+        *right_freq_map.entry(num).or_insert(0) += 1;
+    }
+
+    lists
+        .left
+        .iter()
+        .map(|&num| {
+            let count = *right_freq_map.get(&num).unwrap_or(&0);
+            u64::from(num) * count
+        })
+        .sum()
+}
+
+/// The total distance for any [`Number`] type, reporting overflow instead
+/// of wrapping.
+pub fn checked_distance<N: Number>(left: &[N], right: &[N]) -> Result<N, Overflow> {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+    left.sort();
+    right.sort();
+
+    left.iter()
+        .zip(&right)
+        .enumerate()
+        .try_fold(N::zero(), |total, (index, (a, b))| {
+            a.checked_distance(b)
+                .and_then(|distance| total.checked_add(&distance))
+                .ok_or_else(|| Overflow {
+                    metric: "distance",
+                    width: N::NAME,
+                    at: format!("pair {} ({a} and {b})", index + 1),
+                })
+        })
+}
+
+/// The similarity score for any [`Number`] type, reporting overflow instead
+/// of wrapping.
+pub fn checked_similarity<N: Number>(left: &[N], right: &[N]) -> Result<N, Overflow> {
+    let mut counts = BTreeMap::new();
+    for id in right {
+        *counts.entry(id).or_insert(0u64) += 1;
+    }
+
+    left.iter().try_fold(N::zero(), |total, id| {
+        let count = counts.get(id).copied().unwrap_or_default();
+        id.checked_mul_count(count)
+            .and_then(|score| total.checked_add(&score))
+            .ok_or_else(|| Overflow {
+                metric: "similarity",
+                width: N::NAME,
+                at: format!("{id} appearing {count} times"),
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_overflow() {
+        let left = [u32::MAX, u32::MAX];
+        let right = [0, u32::MAX];
+        assert_eq!(checked_distance(&left, &right).map_err(|e| e.metric), Ok(u32::MAX));
+        let overflow = checked_similarity(&left, &right).unwrap_err();
+        assert_eq!(overflow.metric, "similarity");
+        assert_eq!(overflow.at, format!("{} appearing 1 times", u32::MAX));
+
+        let wide = left.map(u64::from);
+        let right = right.map(u64::from);
+        assert_eq!(checked_similarity(&wide, &right), Ok(2 * u64::from(u32::MAX)));
+    }
+}
@@ -0,0 +1,445 @@
+//! Ways of comparing the two lists besides the puzzle's own two.
+//!
+//! Every metric works on the lists as parsed, paired up row by row where
+//! pairing matters (the rank correlations) and as multisets otherwise.
+
+use std::cmp::Reverse;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::number::{Number, Overflow};
+use crate::{checked_distance, checked_similarity};
+
+/// How many rows the contributions report lists when not told otherwise.
+pub const DEFAULT_TOP: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of distances between the lists paired up smallest to largest.
+    Distance,
+    /// Each left ID times how often it appears on the right.
+    Similarity,
+    /// Shared distinct IDs over all distinct IDs.
+    Jaccard,
+    /// Multiset Jaccard: shared occurrences over all occurrences.
+    Overlap,
+    /// Kendall's tau-b between the columns row by row.
+    KendallTau,
+    /// Spearman's rank correlation between the columns row by row.
+    Spearman,
+    /// Levenshtein distance between the sorted lists. Quadratic in time.
+    EditDistance,
+    /// The pairs and IDs adding the most to distance and similarity.
+    Contributions { top: usize },
+}
+
+impl Metric {
+    pub const NAMES: [&'static str; 8] = [
+        "distance",
+        "similarity",
+        "jaccard",
+        "overlap",
+        "kendall-tau",
+        "spearman",
+        "edit-distance",
+        "contributions",
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::Distance => "distance",
+            Metric::Similarity => "similarity",
+            Metric::Jaccard => "jaccard",
+            Metric::Overlap => "overlap",
+            Metric::KendallTau => "kendall-tau",
+            Metric::Spearman => "spearman",
+            Metric::EditDistance => "edit-distance",
+            Metric::Contributions { .. } => "contributions",
+        }
+    }
+
+    pub fn compute<N: Number>(self, left: &[N], right: &[N]) -> Result<Value<N>, Overflow> {
+        Ok(match self {
+            Metric::Distance => Value::Number(checked_distance(left, right)?),
+            Metric::Similarity => Value::Number(checked_similarity(left, right)?),
+            Metric::Jaccard => Value::Ratio(jaccard(left, right)),
+            Metric::Overlap => Value::Ratio(overlap(left, right)),
+            Metric::KendallTau => Value::Ratio(kendall_tau(left, right)),
+            Metric::Spearman => Value::Ratio(spearman(left, right)),
+            Metric::EditDistance => Value::Count(edit_distance(left, right)),
+            Metric::Contributions { top } => Value::Contributions(contributions(left, right, top)?),
+        })
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "distance" => Ok(Metric::Distance),
+            "similarity" => Ok(Metric::Similarity),
+            "jaccard" => Ok(Metric::Jaccard),
+            "overlap" => Ok(Metric::Overlap),
+            "kendall-tau" => Ok(Metric::KendallTau),
+            "spearman" => Ok(Metric::Spearman),
+            "edit-distance" => Ok(Metric::EditDistance),
+            "contributions" => Ok(Metric::Contributions { top: DEFAULT_TOP }),
+            _ => Err(format!(
+                "unknown metric `{s}`, expected one of: {}",
+                Metric::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+/// The result of one metric. Ratios are `None` where they are undefined,
+/// such as a correlation over fewer than two rows.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<N> {
+    Number(N),
+    Ratio(Option<f64>),
+    Count(usize),
+    Contributions(Contributions<N>),
+}
+
+impl<N: Number> Display for Value<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{n}"),
+            Value::Ratio(Some(ratio)) => write!(f, "{ratio:.6}"),
+            Value::Ratio(None) => write!(f, "undefined"),
+            Value::Count(count) => write!(f, "{count}"),
+            Value::Contributions(contributions) => write!(f, "{contributions}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Contributions<N> {
+    /// `(rank, left, right, distance)` for the sorted pairs furthest apart.
+    pub distances: Vec<(usize, N, N, N)>,
+    /// `(id, left count, right count, score)` for the IDs scoring highest.
+    pub similarities: Vec<(N, u64, u64, N)>,
+}
+
+impl<N: Number> Display for Contributions<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "largest distances (sorted pair: left, right -> distance)"
+        )?;
+        for (rank, a, b, distance) in &self.distances {
+            writeln!(f, "  #{rank}: {a}, {b} -> {distance}")?;
+        }
+        write!(f, "largest similarity scores (id: left x right -> score)")?;
+        for (id, left, right, score) in &self.similarities {
+            write!(f, "\n  {id}: {left} x {right} -> {score}")?;
+        }
+        Ok(())
+    }
+}
+
+fn sorted<N: Ord + Clone>(values: &[N]) -> Vec<N> {
+    let mut values = values.to_vec();
+    values.sort();
+    values
+}
+
+/// `(id, count)` for each distinct ID of a sorted list.
+fn groups<N: Ord>(sorted: &[N]) -> Vec<(&N, u64)> {
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|group| (&group[0], group.len() as u64))
+        .collect()
+}
+
+/// Walks the distinct IDs of both lists in order, giving each ID with its
+/// count on either side.
+fn joined<'a, N: Ord>(left: &'a [N], right: &'a [N]) -> Vec<(&'a N, u64, u64)> {
+    let (left, right) = (groups(left), groups(right));
+    let (mut i, mut j) = (0, 0);
+    let mut joined = Vec::with_capacity(left.len().max(right.len()));
+
+    loop {
+        match (left.get(i), right.get(j)) {
+            (Some(&(a, count_a)), Some(&(b, count_b))) if a == b => {
+                joined.push((a, count_a, count_b));
+                i += 1;
+                j += 1;
+            }
+            (Some(&(a, count)), Some(&(b, _))) if a < b => {
+                joined.push((a, count, 0));
+                i += 1;
+            }
+            (Some(&(a, count)), None) => {
+                joined.push((a, count, 0));
+                i += 1;
+            }
+            (_, Some(&(b, count))) => {
+                joined.push((b, 0, count));
+                j += 1;
+            }
+            (None, None) => break,
+        }
+    }
+
+    joined
+}
+
+fn ratio(numerator: u64, denominator: u64) -> Option<f64> {
+    (denominator > 0).then(|| numerator as f64 / denominator as f64)
+}
+
+fn jaccard<N: Number>(left: &[N], right: &[N]) -> Option<f64> {
+    let (left, right) = (sorted(left), sorted(right));
+    let joined = joined(&left, &right);
+    let shared = joined.iter().filter(|(_, a, b)| *a > 0 && *b > 0).count();
+    ratio(shared as u64, joined.len() as u64)
+}
+
+fn overlap<N: Number>(left: &[N], right: &[N]) -> Option<f64> {
+    let (left, right) = (sorted(left), sorted(right));
+    let joined = joined(&left, &right);
+    let shared = joined.iter().map(|(_, a, b)| a.min(b)).sum();
+    let total = joined.iter().map(|(_, a, b)| a.max(b)).sum();
+    ratio(shared, total)
+}
+
+/// 1-based ranks, with tied values sharing the mean of their ranks.
+fn ranks<N: Ord>(values: &[N]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].cmp(&values[b]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    for group in order.chunk_by(|&a, &b| values[a] == values[b]) {
+        let mean = (start + 1 + start + group.len()) as f64 / 2.0;
+        for &index in group {
+            ranks[index] = mean;
+        }
+        start += group.len();
+    }
+    ranks
+}
+
+fn spearman<N: Number>(left: &[N], right: &[N]) -> Option<f64> {
+    let n = left.len().min(right.len());
+    if n < 2 {
+        return None;
+    }
+    let (x, y) = (ranks(&left[..n]), ranks(&right[..n]));
+
+    let mean = (n + 1) as f64 / 2.0;
+    let (mut covariance, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(&y) {
+        covariance += (a - mean) * (b - mean);
+        var_x += (a - mean).powi(2);
+        var_y += (b - mean).powi(2);
+    }
+    let denominator = (var_x * var_y).sqrt();
+    (denominator > 0.0).then(|| covariance / denominator)
+}
+
+/// Kendall's tau-b using Knight's O(n log n) algorithm: sort the rows by
+/// left then right, and count the discordant pairs as the swaps a merge sort
+/// of the right column needs.
+fn kendall_tau<N: Number>(left: &[N], right: &[N]) -> Option<f64> {
+    let mut rows: Vec<(&N, &N)> = left.iter().zip(right).collect();
+    let n = rows.len() as u64;
+    if n < 2 {
+        return None;
+    }
+    rows.sort();
+
+    let tied_pairs = |count: u64| count * (count - 1) / 2;
+    let all = tied_pairs(n);
+    let tied_left: u64 = rows
+        .chunk_by(|a, b| a.0 == b.0)
+        .map(|group| tied_pairs(group.len() as u64))
+        .sum();
+    let tied_both: u64 = rows
+        .chunk_by(|a, b| a == b)
+        .map(|group| tied_pairs(group.len() as u64))
+        .sum();
+
+    let mut ys: Vec<&N> = rows.iter().map(|&(_, y)| y).collect();
+    let swaps = merge_sort_swaps(&mut ys);
+    let tied_right: u64 = ys
+        .chunk_by(|a, b| a == b)
+        .map(|group| tied_pairs(group.len() as u64))
+        .sum();
+
+    let numerator =
+        all as f64 - tied_left as f64 - tied_right as f64 + tied_both as f64 - 2.0 * swaps as f64;
+    let denominator = ((all - tied_left) as f64 * (all - tied_right) as f64).sqrt();
+    (denominator > 0.0).then(|| numerator / denominator)
+}
+
+/// Sorts `values`, returning how many out-of-order pairs it contained.
+fn merge_sort_swaps<T: Ord + Copy>(values: &mut [T]) -> u64 {
+    if values.len() < 2 {
+        return 0;
+    }
+    let middle = values.len() / 2;
+    let mut swaps =
+        merge_sort_swaps(&mut values[..middle]) + merge_sort_swaps(&mut values[middle..]);
+
+    let mut merged = Vec::with_capacity(values.len());
+    let (mut i, mut j) = (0, middle);
+    while i < middle && j < values.len() {
+        if values[j] < values[i] {
+            merged.push(values[j]);
+            swaps += (middle - i) as u64;
+            j += 1;
+        } else {
+            merged.push(values[i]);
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&values[i..middle]);
+    merged.extend_from_slice(&values[j..]);
+    values.copy_from_slice(&merged);
+    swaps
+}
+
+fn edit_distance<N: Number>(left: &[N], right: &[N]) -> usize {
+    let (left, right) = (sorted(left), sorted(right));
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current = vec![0; right.len() + 1];
+
+    for (i, a) in left.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b) in right.iter().enumerate() {
+            let substitute = previous[j] + usize::from(a != b);
+            current[j + 1] = substitute.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[right.len()]
+}
+
+fn contributions<N: Number>(
+    left: &[N],
+    right: &[N],
+    top: usize,
+) -> Result<Contributions<N>, Overflow> {
+    let (left, right) = (sorted(left), sorted(right));
+
+    let mut distances = left
+        .iter()
+        .zip(&right)
+        .enumerate()
+        .map(|(index, (a, b))| {
+            let distance = a.checked_distance(b).ok_or_else(|| Overflow {
+                metric: "distance",
+                width: N::NAME,
+                at: format!("pair {} ({a} and {b})", index + 1),
+            })?;
+            Ok((index + 1, a.clone(), b.clone(), distance))
+        })
+        .collect::<Result<Vec<_>, Overflow>>()?;
+    distances.sort_by(|a, b| b.3.cmp(&a.3).then(a.0.cmp(&b.0)));
+    distances.truncate(top);
+
+    let mut similarities = joined(&left, &right)
+        .into_iter()
+        .filter(|&(_, a, b)| a > 0 && b > 0)
+        .map(|(id, a, b)| {
+            let score = a
+                .checked_mul(b)
+                .and_then(|count| id.checked_mul_count(count))
+                .ok_or_else(|| Overflow {
+                    metric: "similarity",
+                    width: N::NAME,
+                    at: format!("{id} appearing {a} x {b} times"),
+                })?;
+            Ok((id.clone(), a, b, score))
+        })
+        .collect::<Result<Vec<_>, Overflow>>()?;
+    similarities.sort_by_key(|entry| Reverse(entry.3.clone()));
+    similarities.truncate(top);
+
+    Ok(Contributions {
+        distances,
+        similarities,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: [u32; 6] = [3, 4, 2, 1, 3, 3];
+    const RIGHT: [u32; 6] = [4, 3, 5, 3, 9, 3];
+
+    fn ratio(metric: Metric, left: &[u32], right: &[u32]) -> f64 {
+        match metric.compute(left, right) {
+            Ok(Value::Ratio(Some(ratio))) => ratio,
+            other => panic!("expected a ratio, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_puzzle_metrics() {
+        assert_eq!(
+            Metric::Distance.compute(&LEFT, &RIGHT),
+            Ok(Value::Number(11))
+        );
+        assert_eq!(
+            Metric::Similarity.compute(&LEFT, &RIGHT),
+            Ok(Value::Number(31))
+        );
+    }
+
+    #[test]
+    fn test_overlap() {
+        assert!((ratio(Metric::Jaccard, &LEFT, &RIGHT) - 2.0 / 6.0).abs() < 1e-9);
+        assert!((ratio(Metric::Overlap, &LEFT, &RIGHT) - 0.5).abs() < 1e-9);
+        assert_eq!(
+            Metric::Jaccard.compute::<u32>(&[], &[]),
+            Ok(Value::Ratio(None))
+        );
+    }
+
+    #[test]
+    fn test_rank_correlation() {
+        let x = [1, 2, 3, 4, 5];
+        let y = [2, 1, 4, 3, 5];
+        assert!((ratio(Metric::KendallTau, &x, &y) - 0.6).abs() < 1e-9);
+        assert!((ratio(Metric::Spearman, &x, &y) - 0.8).abs() < 1e-9);
+
+        let reversed = [5, 4, 3, 2, 1];
+        assert!((ratio(Metric::KendallTau, &x, &reversed) + 1.0).abs() < 1e-9);
+        assert!((ratio(Metric::Spearman, &x, &reversed) + 1.0).abs() < 1e-9);
+
+        // Ties on both sides: tau-b = 2 / sqrt(5 * 5).
+        let x = [1, 1, 2, 3];
+        let y = [1, 2, 2, 3];
+        assert!((ratio(Metric::KendallTau, &x, &y) - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(
+            Metric::EditDistance.compute(&LEFT, &RIGHT),
+            Ok(Value::Count(4))
+        );
+        assert_eq!(
+            Metric::EditDistance.compute(&LEFT, &[]),
+            Ok(Value::Count(6))
+        );
+    }
+
+    #[test]
+    fn test_contributions() {
+        let Ok(Value::Contributions(report)) =
+            Metric::Contributions { top: 2 }.compute(&LEFT, &RIGHT)
+        else {
+            panic!("expected a contributions report");
+        };
+        assert_eq!(report.distances, [(6, 4, 9, 5), (1, 1, 3, 2)]);
+        assert_eq!(report.similarities, [(3, 3, 3, 27), (4, 1, 1, 4)]);
+    }
+}
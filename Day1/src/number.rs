@@ -0,0 +1,110 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+
+/// A location ID type the lists can be compared in. Arithmetic is checked,
+/// returning `None` where the result does not fit.
+pub trait Number: Clone + Ord + Display + FromStr<Err: Display> {
+    /// Shown in overflow reports.
+    const NAME: &'static str;
+
+    fn zero() -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_distance(&self, other: &Self) -> Option<Self>;
+    fn checked_mul_count(&self, count: u64) -> Option<Self>;
+}
+
+macro_rules! number {
+    ($type:ty, $name:literal, |$a:ident, $b:ident| $distance:expr) => {
+        impl Number for $type {
+            const NAME: &'static str = $name;
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$type>::checked_add(*self, *other)
+            }
+
+            fn checked_distance(&self, other: &Self) -> Option<Self> {
+                let ($a, $b) = (*self, *other);
+                $distance
+            }
+
+            fn checked_mul_count(&self, count: u64) -> Option<Self> {
+                <$type>::checked_mul(*self, count.try_into().ok()?)
+            }
+        }
+    };
+}
+
+number!(u32, "u32", |a, b| Some(a.abs_diff(b)));
+number!(u64, "u64", |a, b| Some(a.abs_diff(b)));
+number!(i64, "i64", |a, b| a.checked_sub(b)?.checked_abs());
+
+impl Number for BigInt {
+    const NAME: &'static str = "bigint";
+
+    fn zero() -> Self {
+        BigInt::ZERO
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_distance(&self, other: &Self) -> Option<Self> {
+        Some((self - other).magnitude().clone().into())
+    }
+
+    fn checked_mul_count(&self, count: u64) -> Option<Self> {
+        Some(self * count)
+    }
+}
+
+/// A metric that no longer fits in the chosen number type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub metric: &'static str,
+    pub width: &'static str,
+    /// The step that overflowed.
+    pub at: String,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} overflows {} at {} (try a wider number type)",
+            self.metric, self.width, self.at
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(3u32.checked_distance(&7), Some(4));
+        assert_eq!(i64::MIN.checked_distance(&1), None);
+        assert_eq!((-3i64).checked_distance(&4), Some(7));
+        assert_eq!(u32::MAX.checked_mul_count(2), None);
+        assert_eq!(5u32.checked_mul_count(u64::MAX), None);
+
+        let big = BigInt::from(u64::MAX);
+        assert_eq!(
+            big.checked_mul_count(2),
+            Some(BigInt::from(u64::MAX) * 2u32)
+        );
+        assert_eq!(
+            BigInt::from(-3).checked_distance(&BigInt::from(4)),
+            Some(BigInt::from(7))
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use util::ParseError;
+
+use crate::number::Number;
+use crate::DAY;
+
+/// What separates the columns of a row. Fields split on a character are
+/// trimmed; quoting is not supported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Delimiter {
+    #[default]
+    Whitespace,
+    Char(char),
+}
+
+impl Delimiter {
+    pub const CSV: Self = Delimiter::Char(',');
+    pub const TSV: Self = Delimiter::Char('\t');
+
+    fn fields<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        match self {
+            Delimiter::Whitespace => line.split_whitespace().collect(),
+            Delimiter::Char(c) => line.split(*c).map(str::trim).collect(),
+        }
+    }
+}
+
+impl FromStr for Delimiter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "whitespace" => Ok(Delimiter::Whitespace),
+            "csv" => Ok(Delimiter::CSV),
+            "tsv" => Ok(Delimiter::TSV),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(Delimiter::Char(c)),
+                    _ => Err(format!(
+                        "`{s}` is not a delimiter; use whitespace, csv, tsv or a single character"
+                    )),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Delimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Delimiter::Whitespace => write!(f, "whitespace"),
+            Delimiter::CSV => write!(f, "csv"),
+            Delimiter::TSV => write!(f, "tsv"),
+            Delimiter::Char(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+/// How to pull the two lists out of a table. The default reads the puzzle
+/// format: the first two whitespace-separated columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schema {
+    pub delimiter: Delimiter,
+    /// How many columns every row must have; any number when unset.
+    pub columns: Option<usize>,
+    /// The zero-based columns holding the left and right list.
+    pub pair: (usize, usize),
+    /// Whether the first line is a header to skip.
+    pub header: bool,
+}
+
+impl Default for Schema {
+    fn default() -> Self {
+        Self {
+            delimiter: Delimiter::Whitespace,
+            columns: None,
+            pair: (0, 1),
+            header: false,
+        }
+    }
+}
+
+impl Schema {
+    pub fn read<N: Number>(&self, input: &str) -> Result<(Vec<N>, Vec<N>), ParseError> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for line in input.lines().skip(usize::from(self.header)) {
+            let (a, b) = self.row(input, line)?;
+            left.push(a);
+            right.push(b);
+        }
+
+        Ok((left, right))
+    }
+
+    /// Parses the selected pair out of one `line` of `input`, which errors
+    /// are reported against.
+    pub fn row<N: Number>(&self, input: &str, line: &str) -> Result<(N, N), ParseError> {
+        let fields = self.delimiter.fields(line);
+        let needed = self.pair.0.max(self.pair.1) + 1;
+        let expected = self.columns.unwrap_or(needed);
+        if fields.len() < needed || self.columns.is_some_and(|n| n != fields.len()) {
+            let end = &line[line.trim_end().len()..];
+            let message = format!("expected {expected} columns, found {}", fields.len());
+            return Err(ParseError::at_token(DAY, input, end, message));
+        }
+
+        let id = |token: &str| {
+            token.parse::<N>().map_err(|e| {
+                let message = format!("`{token}` is not a location ID ({}): {e}", N::NAME);
+                ParseError::at_token(DAY, input, token, message)
+            })
+        };
+        Ok((id(fields[self.pair.0])?, id(fields[self.pair.1])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use super::*;
+
+    #[test]
+    fn test_csv_pair() {
+        let input = "name,a,b\nx, 3 ,-4\ny,-1,2\n";
+        let schema = Schema {
+            delimiter: "csv".parse().unwrap(),
+            columns: Some(3),
+            pair: (2, 1),
+            header: true,
+        };
+        let (left, right) = schema.read::<i64>(input).unwrap();
+        assert_eq!((left, right), (vec![-4, 2], vec![3, -1]));
+
+        let (left, _) = schema.read::<BigInt>(input).unwrap();
+        assert_eq!(left, [BigInt::from(-4), BigInt::from(2)]);
+
+        let error = schema.read::<u32>(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+    }
+
+    #[test]
+    fn test_column_count() {
+        let schema = Schema {
+            delimiter: Delimiter::TSV,
+            columns: Some(3),
+            ..Schema::default()
+        };
+        let error = schema.read::<u32>("1\t2\t3\n4\t5\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(error.message.starts_with("expected 3 columns, found 2"));
+    }
+}
@@ -0,0 +1,408 @@
+//! Historian Hysteria over inputs too large to hold in memory.
+//!
+//! Each column is cut into runs of at most [`StreamConfig::run_len`] IDs,
+//! which are sorted and spilled to temporary files. Both answers then come
+//! from k-way merges of those runs: the distance pairs the merged columns
+//! up in order, and the similarity score joins equal IDs of the two merged
+//! columns, so only one ID per run is held in memory at a time.
+//!
+//! Spilled runs are kept closed until they are merged, and no more than
+//! [`StreamConfig::fan_in`] runs are merged at once, so that bounds the
+//! files each column has open. Whenever that many runs of the same size
+//! pile up they are merged into one larger run, and the smallest runs are
+//! merged the same way until few enough remain for the final merge.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+
+use tempfile::{NamedTempFile, TempPath};
+use util::ParseError;
+
+use crate::schema::Schema;
+
+#[derive(Clone, Debug)]
+pub struct StreamConfig {
+    /// How many IDs of each column to sort in memory before spilling a run.
+    pub run_len: usize,
+    /// Where to put spilled runs; the system temporary directory if unset.
+    pub spill_dir: Option<PathBuf>,
+    /// How many runs to merge at once, at least 2. Each column has at most
+    /// this many files open.
+    pub fan_in: usize,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            run_len: 1 << 20,
+            spill_dir: None,
+            fan_in: 64,
+        }
+    }
+}
+
+/// Both answers for a streamed input, each wide enough that no number of
+/// `u32` pairs can overflow it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub pairs: u64,
+    pub distance: u128,
+    pub similarity: u128,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "could not stream location IDs: {e}"),
+            StreamError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(e) => Some(e),
+            StreamError::Parse(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+/// Computes the total distance and similarity score of the lists in
+/// `reader`, one pair of IDs per line.
+pub fn totals(reader: impl BufRead, config: &StreamConfig) -> Result<Totals, StreamError> {
+    let (left, right) = split_runs(reader, config)?;
+
+    let mut totals = Totals::default();
+    for (a, b) in left.merge()?.zip(right.merge()?) {
+        totals.pairs += 1;
+        totals.distance += u128::from(a?.abs_diff(b?));
+    }
+    totals.similarity = similarity(left.merge()?, right.merge()?)?;
+
+    Ok(totals)
+}
+
+fn split_runs(
+    mut reader: impl BufRead,
+    config: &StreamConfig,
+) -> Result<(Runs, Runs), StreamError> {
+    let mut left = Runs::new(config);
+    let mut right = Runs::new(config);
+
+    let schema = Schema::default();
+    let mut line = String::new();
+    let mut number = 0;
+    while reader.read_line(&mut line)? > 0 {
+        number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+        let (a, b) = schema.row(text, text).map_err(|mut e| {
+            e.line = number;
+            StreamError::Parse(e)
+        })?;
+        left.push(a)?;
+        right.push(b)?;
+        line.clear();
+    }
+
+    left.finish()?;
+    right.finish()?;
+    Ok((left, right))
+}
+
+/// Sums `id * left count * right count` over the IDs both sorted streams
+/// contain.
+fn similarity(
+    left: impl Iterator<Item = io::Result<u32>>,
+    right: impl Iterator<Item = io::Result<u32>>,
+) -> io::Result<u128> {
+    let mut left = Groups::new(left);
+    let mut right = Groups::new(right);
+
+    let mut total = 0;
+    let (mut a, mut b) = (left.next_group()?, right.next_group()?);
+    while let (Some((id_a, count_a)), Some((id_b, count_b))) = (a, b) {
+        if id_a < id_b {
+            a = left.next_group()?;
+        } else if id_b < id_a {
+            b = right.next_group()?;
+        } else {
+            total += u128::from(id_a) * u128::from(count_a) * u128::from(count_b);
+            a = left.next_group()?;
+            b = right.next_group()?;
+        }
+    }
+
+    Ok(total)
+}
+
+/// Collapses a sorted stream into `(id, count)` pairs.
+struct Groups<I: Iterator<Item = io::Result<u32>>> {
+    ids: Peekable<I>,
+}
+
+impl<I: Iterator<Item = io::Result<u32>>> Groups<I> {
+    fn new(ids: I) -> Self {
+        Self {
+            ids: ids.peekable(),
+        }
+    }
+
+    fn next_group(&mut self) -> io::Result<Option<(u32, u64)>> {
+        let Some(id) = self.ids.next().transpose()? else {
+            return Ok(None);
+        };
+        let mut count = 1;
+        while let Some(Ok(next)) = self.ids.peek() {
+            if *next != id {
+                break;
+            }
+            self.ids.next();
+            count += 1;
+        }
+        Ok(Some((id, count)))
+    }
+}
+
+/// The sorted runs of one column. A column that fits in a single run never
+/// touches the disk.
+struct Runs {
+    run_len: usize,
+    spill_dir: Option<PathBuf>,
+    fan_in: usize,
+    buffer: Vec<u32>,
+    /// Spilled runs, from the most merged to the least.
+    spilled: Vec<Run>,
+}
+
+struct Run {
+    /// Removed when the run is dropped.
+    path: TempPath,
+    /// How many times the IDs in this run have been merged.
+    level: u32,
+}
+
+impl Runs {
+    fn new(config: &StreamConfig) -> Self {
+        Self {
+            run_len: config.run_len.max(1),
+            spill_dir: config.spill_dir.clone(),
+            fan_in: config.fan_in.max(2),
+            buffer: Vec::new(),
+            spilled: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, id: u32) -> io::Result<()> {
+        self.buffer.push(id);
+        if self.buffer.len() >= self.run_len {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Spills what is left and merges the smallest runs until at most
+    /// `fan_in` remain for [`Runs::merge`].
+    fn finish(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        if !self.spilled.is_empty() && !self.buffer.is_empty() {
+            self.spill()?;
+        }
+        while self.spilled.len() > self.fan_in {
+            let excess = self.spilled.len() - self.fan_in;
+            self.merge_last((excess + 1).min(self.fan_in))?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        let path = write_run(self.spill_dir.as_deref(), self.buffer.drain(..).map(Ok))?;
+        self.spilled.push(Run { path, level: 0 });
+
+        // Only the newest runs can have reached `fan_in` of one level.
+        while let Some(last) = self.spilled.len().checked_sub(self.fan_in) {
+            let level = self.spilled[last].level;
+            if self.spilled[last..].iter().any(|run| run.level != level) {
+                break;
+            }
+            self.merge_last(self.fan_in)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the last `count` runs with one run holding all their IDs.
+    fn merge_last(&mut self, count: usize) -> io::Result<()> {
+        let runs = self.spilled.split_off(self.spilled.len() - count);
+        let level = runs.iter().map(|run| run.level).max().unwrap_or_default() + 1;
+        let path = write_run(self.spill_dir.as_deref(), Merge::of_runs(&runs)?)?;
+        self.spilled.push(Run { path, level });
+        Ok(())
+    }
+
+    /// Merges every run into one sorted stream. Can be called again to
+    /// start over from the beginning.
+    fn merge(&self) -> io::Result<Merge<'_>> {
+        if self.spilled.is_empty() {
+            return Merge::new(vec![Source::Memory(self.buffer.iter())]);
+        }
+        Merge::of_runs(&self.spilled)
+    }
+}
+
+/// Writes sorted `ids` to a new temporary file and closes it.
+fn write_run(
+    spill_dir: Option<&Path>,
+    ids: impl Iterator<Item = io::Result<u32>>,
+) -> io::Result<TempPath> {
+    let file = match spill_dir {
+        Some(dir) => NamedTempFile::new_in(dir)?,
+        None => NamedTempFile::new()?,
+    };
+
+    let mut writer = BufWriter::new(file);
+    for id in ids {
+        writer.write_all(&id?.to_le_bytes())?;
+    }
+    let file = writer.into_inner().map_err(|e| e.into_error())?;
+    Ok(file.into_temp_path())
+}
+
+enum Source<'a> {
+    Memory(std::slice::Iter<'a, u32>),
+    File(BufReader<File>),
+}
+
+impl Source<'_> {
+    fn next(&mut self) -> io::Result<Option<u32>> {
+        match self {
+            Source::Memory(ids) => Ok(ids.next().copied()),
+            Source::File(reader) => {
+                let mut bytes = [0; 4];
+                match reader.read_exact(&mut bytes) {
+                    Ok(()) => Ok(Some(u32::from_le_bytes(bytes))),
+                    Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    }
+}
+
+struct Merge<'a> {
+    sources: Vec<Source<'a>>,
+    heap: BinaryHeap<Reverse<(u32, usize)>>,
+}
+
+impl<'a> Merge<'a> {
+    fn new(mut sources: Vec<Source<'a>>) -> io::Result<Self> {
+        let mut heap = BinaryHeap::with_capacity(sources.len());
+        for (index, source) in sources.iter_mut().enumerate() {
+            if let Some(id) = source.next()? {
+                heap.push(Reverse((id, index)));
+            }
+        }
+        Ok(Self { sources, heap })
+    }
+
+    fn of_runs(runs: &[Run]) -> io::Result<Self> {
+        let mut sources = Vec::with_capacity(runs.len());
+        for run in runs {
+            sources.push(Source::File(BufReader::new(File::open(&run.path)?)));
+        }
+        Self::new(sources)
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<u32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((id, index)) = self.heap.pop()?;
+        match self.sources[index].next() {
+            Ok(Some(next)) => self.heap.push(Reverse((next, index))),
+            Ok(None) => {}
+            Err(e) => return Some(Err(e)),
+        }
+        Some(Ok(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_lists, solution_part1, solution_part2};
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_matches_in_memory() {
+        let lists = parse_lists(EXAMPLE).unwrap();
+        for run_len in [1, 2, 4, 100] {
+            let config = StreamConfig {
+                run_len,
+                ..StreamConfig::default()
+            };
+            let totals = totals(EXAMPLE.as_bytes(), &config).unwrap();
+            assert_eq!(totals.pairs, 6);
+            assert_eq!(totals.distance, u128::from(solution_part1(&lists)));
+            assert_eq!(totals.similarity, u128::from(solution_part2(&lists)));
+        }
+    }
+
+    #[test]
+    fn test_merge_passes() {
+        let input: String = (0..500u32)
+            .map(|i| format!("{}   {}\n", i * 7919 % 263, i * 104_729 % 311))
+            .collect();
+        let lists = parse_lists(&input).unwrap();
+        for fan_in in [2, 3, 64] {
+            let config = StreamConfig {
+                run_len: 3,
+                fan_in,
+                ..StreamConfig::default()
+            };
+
+            let mut runs = Runs::new(&config);
+            for id in &lists.left {
+                runs.push(*id).unwrap();
+            }
+            runs.finish().unwrap();
+            assert!(runs.spilled.len() <= fan_in);
+            let mut sorted = lists.left.clone();
+            sorted.sort_unstable();
+            let merged: Vec<u32> = runs.merge().unwrap().map(Result::unwrap).collect();
+            assert_eq!(merged, sorted, "fan-in {fan_in}");
+
+            let totals = totals(input.as_bytes(), &config).unwrap();
+            assert_eq!(totals.distance, u128::from(solution_part1(&lists)));
+            assert_eq!(totals.similarity, u128::from(solution_part2(&lists)));
+        }
+    }
+
+    #[test]
+    fn test_parse_error_line() {
+        let config = StreamConfig::default();
+        let Err(StreamError::Parse(error)) = totals("1 2\n3 4\n5\n".as_bytes(), &config) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((error.line, error.column), (3, 2));
+    }
+}
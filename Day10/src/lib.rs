@@ -0,0 +1,145 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+
+use util::grid::Grid;
+use util::point::Point;
+use util::{ParseError, Solution};
+
+pub struct HoofIt;
+
+const DAY: u8 = HoofIt::DAY;
+
+impl Solution for HoofIt {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = TopographicMap;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        TopographicMap::from_input(input)
+    }
+
+    fn part1(topo_map: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(topo_map.total_score())
+    }
+
+    fn part2(topo_map: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(topo_map.total_rating())
+    }
+}
+
+#[derive(Debug)]
+pub struct TopographicMap {
+    map: Grid<u32>,
+}
+
+impl TopographicMap {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let input = input.trim_end();
+        let chars: Grid<char> = input
+            .parse()
+            .map_err(|e| ParseError::from_grid(DAY, input, e))?;
+        if let Some((point, c)) = chars.iter().find(|(_, c)| !c.is_ascii_digit()) {
+            let message = format!("expected a height from 0 to 9, found `{c}`");
+            return Err(ParseError::at_point(DAY, input, point, message));
+        }
+
+        let map = chars.map(|c| c.to_digit(10).unwrap_or_default());
+        Ok(TopographicMap { map })
+    }
+
+    fn find_trailheads(&self) -> Vec<Point> {
+        self.map.find_all(&0).collect()
+    }
+
+    fn score_trailhead(&self, start: Point) -> usize {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        let mut score = 0;
+
+        queue.push_back(start);
+        visited.insert(start);
+
+        while let Some(point) = queue.pop_front() {
+            let current_height = self.map[point];
+            if current_height == 9 {
+                score += 1;
+            }
+
+            for (neighbour, &height) in self.map.von_neumann_neighbours(point) {
+                if height == current_height + 1 && visited.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        score
+    }
+
+    fn rating_trailhead(&self, start: Point) -> usize {
+        let mut queue = VecDeque::new();
+        let mut rating = 0;
+
+        queue.push_back(start);
+
+        while let Some(point) = queue.pop_front() {
+            let current_height = self.map[point];
+            if current_height == 9 {
+                rating += 1;
+                continue;
+            }
+
+            for (neighbour, &height) in self.map.von_neumann_neighbours(point) {
+                if height == current_height + 1 {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        rating
+    }
+
+    pub fn total_score(&self) -> usize {
+        let trailheads = self.find_trailheads();
+        trailheads
+            .iter()
+            .map(|&trailhead| self.score_trailhead(trailhead))
+            .sum()
+    }
+
+    pub fn total_rating(&self) -> usize {
+        let trailheads = self.find_trailheads();
+        trailheads
+            .iter()
+            .map(|&trailhead| self.rating_trailhead(trailhead))
+            .sum()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_total_score() {
+        let input = "0123\n1234\n8765\n9876";
+        let topo_map = TopographicMap::from_input(input).unwrap();
+        assert_eq!(topo_map.total_score(), 1);
+    }
+
+    #[test]
+    fn test_larger_example() {
+        let input = "89010123\n78121874\n87430965\n96549874\n45678903\n32019012\n01329801\n10456732";
+        let topo_map = TopographicMap::from_input(input).unwrap();
+        assert_eq!(topo_map.total_score(), 36);
+        assert_eq!(topo_map.total_rating(), 81);
+    }
+
+    #[test]
+    fn test_invalid_height() {
+        let error = TopographicMap::from_input("0123\n12.4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use util::{ParseError, Solution};
+
+pub struct PlutonianPebbles;
+
+const DAY: u8 = PlutonianPebbles::DAY;
+
+impl Solution for PlutonianPebbles {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = Vec<usize>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part_one(stones))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part_two(stones))
+    }
+}
+
+pub fn split_number(num: usize) -> (usize, usize) {
+    let num_str = num.to_string();
+    let mid = num_str.len() / 2;
+    let left = num_str[..mid].parse::<usize>().unwrap_or(0);
+    let right = num_str[mid..].parse::<usize>().unwrap_or(0);
+    (left, right)
+}
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|s| {
+            s.parse::<usize>().map_err(|e| {
+                let message = format!("`{s}` is not an engraving: {e}");
+                ParseError::at_token(DAY, input, s, message)
+            })
+        })
+        .collect()
+}
+
+/// Counts the stones after `blinks` without materialising them, tracking
+/// how many stones carry each engraving instead. Both parts use this; the
+/// original solver built the full list of stones, which does not fit in
+/// memory for part 2's 75 blinks.
+pub fn count_stones(stones: &[usize], blinks: usize) -> usize {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for &stone in stones {
+        *counts.entry(stone).or_default() += 1;
+    }
+
+    for _ in 0..blinks {
+        let mut next = HashMap::with_capacity(counts.len());
+        for (stone, count) in counts {
+            if stone == 0 {
+                *next.entry(1).or_default() += count;
+            } else if stone.to_string().len() % 2 == 0 {
+                let (left, right) = split_number(stone);
+                *next.entry(left).or_default() += count;
+                *next.entry(right).or_default() += count;
+            } else {
+                *next.entry(stone * 2024).or_default() += count;
+            }
+        }
+        counts = next;
+    }
+
+    counts.values().sum()
+}
+
+pub fn part_one(input: &[usize]) -> usize {
+    count_stones(input, 25)
+}
+
+pub fn part_two(input: &[usize]) -> usize {
+    count_stones(input, 75)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The original solver, which builds every stone. Its memory grows with
+    /// the stone count, so 75 blinks are out of reach; it stays as the
+    /// reference [`count_stones`] is checked against.
+    fn evolve_stones(
+        stones: &[usize],
+        blinks: usize,
+        cache: &mut HashMap<(usize, usize), Vec<usize>>,
+    ) -> Vec<usize> {
+        let mut stones = stones.to_vec();
+        for _ in 0..blinks {
+            let mut new_stones = Vec::new();
+            for &stone in &stones {
+                if let Some(cached_result) = cache.get(&(stone, blinks)) {
+                    new_stones.extend(cached_result.clone());
+                } else {
+                    let result = if stone == 0 {
+                        vec![1]
+                    } else if stone.to_string().len() % 2 == 0 {
+                        let (left, right) = split_number(stone);
+                        vec![left, right]
+                    } else {
+                        vec![stone * 2024]
+                    };
+                    cache.insert((stone, blinks), result.clone());
+                    new_stones.extend(result);
+                }
+            }
+            stones = new_stones;
+        }
+        stones
+    }
+
+    #[test]
+    fn test_split_number() {
+        assert_eq!(split_number(1234), (12, 34));
+        assert_eq!(split_number(5678), (56, 78));
+        assert_eq!(split_number(0), (0, 0));
+    }
+
+    #[test]
+    fn test_evolve_stones() {
+        let stones = vec![1234, 5678, 0];
+        let mut cache = HashMap::new();
+        let result = evolve_stones(&stones, 1, &mut cache);
+        assert_eq!(result, vec![12, 34, 56, 78, 1]);
+    }
+
+    #[test]
+    fn test_count_stones() {
+        let stones = vec![125, 17];
+        let mut cache = HashMap::new();
+        assert_eq!(count_stones(&stones, 6), evolve_stones(&stones, 6, &mut cache).len());
+        assert_eq!(count_stones(&stones, 25), 55312);
+    }
+
+    #[test]
+    fn test_read_input() {
+        let input = "1234 5678 0";
+        let expected = vec![1234, 5678, 0];
+        let result = parse(input);
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = vec![1234, 5678, 0];
+        let mut cache = HashMap::new();
+        assert_eq!(part_one(&input), evolve_stones(&input, 25, &mut cache).len());
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = vec![1234, 5678, 0];
+        assert_eq!(part_two(&input), count_stones(&[12, 34, 56, 78, 1], 74));
+    }
+}
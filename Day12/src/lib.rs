@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use util::grid::Grid;
+use util::point::Point;
+use util::{ParseError, Solution};
+
+pub struct GardenGroups;
+
+impl Solution for GardenGroups {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = Grid<char>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .parse()
+            .map_err(|e| ParseError::from_grid(Self::DAY, input, e))
+    }
+
+    fn part1(grid: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part1(grid))
+    }
+
+    fn part2(grid: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part2(grid))
+    }
+}
+
+pub fn part1(grid: &Grid<char>) -> u32 {
+    get_regions(grid)
+        .iter()
+        .map(|region| region.area * region.perimeter)
+        .sum()
+}
+
+pub fn part2(grid: &Grid<char>) -> u32 {
+    get_regions(grid)
+        .iter()
+        .map(|region| region.area * region.sides())
+        .sum()
+}
+
+fn get_regions(grid: &Grid<char>) -> Vec<Region> {
+    let mut visited = HashSet::new();  
+    let mut regions = Vec::new();
+
+    for (point, &plant) in grid.iter() {
+        if !visited.contains(&point) {
+            let mut region = Region::default();
+            find_region(grid, &mut visited, &mut region, point, plant);
+            regions.push(region);
+        }
+    }
+
+    regions
+}
+
+fn find_region(
+    grid: &Grid<char>,
+    visited: &mut HashSet<Point>,  // ใช้ HashSet แทน FxHashSet
+    region: &mut Region,
+    point: Point,
+    plant: char,
+) -> bool {
+    if grid.get(point) != Some(&plant) {
+        return false;
+    }
+
+    if visited.contains(&point) {
+        return true;
+    }
+
+    visited.insert(point);
+    region.area += 1;
+
+    for neighbor in Point::von_neumann() {
+        let new_point = point + neighbor;
+        if !find_region(grid, visited, region, new_point, plant) {
+            region.perimeter += 1;
+            region.edges.insert(Edge(point, neighbor));
+        }
+    }
+
+    true
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Edge(Point, Point);
+
+#[derive(Default)]
+struct Region {
+    area: u32,
+    perimeter: u32,
+    edges: HashSet<Edge>,
+}
+
+impl Region {
+    fn sides(&self) -> u32 {
+        let mut to_remove = HashSet::new();
+        let mut sorted: Vec<&Edge> = self.edges.iter().collect();
+        sorted.sort();
+
+        for edge in sorted {
+            let sides = match edge.1.x() {
+                0 => [Point::left(), Point::right()],
+                _ => [Point::up(), Point::down()],
+            }
+            .map(|point| Edge(edge.0 + point, edge.1));
+
+            if sides
+                .iter()
+                .any(|fence| self.edges.contains(fence) && !to_remove.contains(fence))
+            {
+                to_remove.insert(*edge);
+            }
+        }
+
+        self.edges.difference(&to_remove).count() as u32
+    }
+}
@@ -0,0 +1,150 @@
+use std::ops::RangeInclusive;
+
+use crate::explain::{self, Trend};
+
+/// The Problem Dampener: tolerates up to `max_removals` bad levels in a
+/// report whose steps must all go one way and change by an amount in
+/// `steps`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dampener {
+    pub steps: RangeInclusive<u32>,
+    pub max_removals: usize,
+}
+
+impl Default for Dampener {
+    fn default() -> Self {
+        Self {
+            steps: 1..=3,
+            max_removals: 1,
+        }
+    }
+}
+
+/// Reads a step range written `MIN..=MAX`, as in `1..=3`.
+pub fn parse_steps(s: &str) -> Result<RangeInclusive<u32>, String> {
+    let error = || format!("`{s}` is not a step range; write it as MIN..=MAX, e.g. 1..=3");
+    let (min, max) = s.split_once("..=").ok_or_else(error)?;
+    let min: u32 = min.trim().parse().map_err(|_| error())?;
+    let max: u32 = max.trim().parse().map_err(|_| error())?;
+    if min > max {
+        return Err(format!("step range `{s}` is empty"));
+    }
+    Ok(min..=max)
+}
+
+impl Dampener {
+    /// Whether `report` is safe as it is, with no levels removed.
+    pub fn is_safe(&self, report: &[i32]) -> bool {
+        explain::check(report.iter().copied().enumerate(), &self.steps)
+            .1
+            .is_none()
+    }
+
+    /// The fewest levels to remove for `report` to be safe, as indices in
+    /// ascending order, or `None` when more than `max_removals` are needed.
+    pub fn removals(&self, report: &[i32]) -> Option<Vec<usize>> {
+        [Trend::Increasing, Trend::Decreasing]
+            .into_iter()
+            .filter_map(|trend| self.removals_towards(report, trend))
+            .min_by_key(Vec::len)
+    }
+
+    /// Each level's best predecessor lies at most `max_removals + 1` back,
+    /// so this takes O(n·k).
+    fn removals_towards(&self, report: &[i32], trend: Trend) -> Option<Vec<usize>> {
+        let k = self.max_removals;
+        let n = report.len();
+        if n == 0 {
+            return Some(Vec::new());
+        }
+
+        // fewest[i]: removals among the levels before `i` when `i` is kept.
+        let mut fewest: Vec<Option<usize>> = vec![None; n];
+        let mut previous = vec![None; n];
+        for i in 0..n {
+            if i <= k {
+                fewest[i] = Some(i);
+            }
+            for j in i.saturating_sub(k + 1)..i {
+                let Some(before) = fewest[j] else {
+                    continue;
+                };
+                let total = before + (i - j - 1);
+                if total <= k
+                    && self.allows(report[j], report[i], trend)
+                    && fewest[i].is_none_or(|best| total < best)
+                {
+                    fewest[i] = Some(total);
+                    previous[i] = Some(j);
+                }
+            }
+        }
+
+        let (total, last) = (n.saturating_sub(k + 1)..n)
+            .filter_map(|i| Some((fewest[i]? + (n - 1 - i), i)))
+            .min()?;
+        if total > k {
+            return None;
+        }
+
+        let mut kept = vec![false; n];
+        let mut level = Some(last);
+        while let Some(i) = level {
+            kept[i] = true;
+            level = previous[i];
+        }
+        Some((0..n).filter(|&i| !kept[i]).collect())
+    }
+
+    fn allows(&self, from: i32, to: i32, trend: Trend) -> bool {
+        let heading = match trend {
+            Trend::Increasing => to > from,
+            Trend::Decreasing => to < from,
+        };
+        heading && self.steps.contains(&to.abs_diff(from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_removal() {
+        let dampener = Dampener::default();
+        assert_eq!(dampener.removals(&[7, 6, 4, 2, 1]), Some(vec![]));
+        assert_eq!(dampener.removals(&[1, 2, 7, 8, 9]), None);
+        assert_eq!(dampener.removals(&[1, 3, 2, 4, 5]), Some(vec![2]));
+        assert_eq!(dampener.removals(&[8, 6, 4, 4, 1]), Some(vec![3]));
+        assert_eq!(dampener.removals(&[]), Some(vec![]));
+        assert_eq!(dampener.removals(&[5]), Some(vec![]));
+    }
+
+    #[test]
+    fn test_k_removals() {
+        let report = [1, 9, 2, 9, 3, 4];
+        let two = Dampener {
+            max_removals: 2,
+            ..Dampener::default()
+        };
+        assert_eq!(Dampener::default().removals(&report), None);
+        assert_eq!(two.removals(&report), Some(vec![1, 3]));
+
+        let wide = Dampener {
+            steps: 1..=8,
+            max_removals: 0,
+        };
+        assert_eq!(wide.removals(&[1, 9, 10]), Some(vec![]));
+        assert_eq!(wide.removals(&[1, 9, 2]), None);
+        assert!(wide.is_safe(&[1, 9, 10]) && !Dampener::default().is_safe(&[1, 9, 10]));
+    }
+
+    #[test]
+    fn test_parse_steps() {
+        assert_eq!(parse_steps("1..=3"), Ok(1..=3));
+        assert_eq!(parse_steps("0..=8"), Ok(0..=8));
+        for invalid in ["1..3", "1-3", "a..=3", "4..=2"] {
+            assert!(parse_steps(invalid).is_err(), "{invalid}");
+        }
+    }
+}
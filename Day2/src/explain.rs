@@ -0,0 +1,233 @@
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+
+use crate::Dampener;
+
+/// Which way a report's levels are heading, set by its first step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+}
+
+impl Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Trend::Increasing => write!(f, "increasing"),
+            Trend::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// A step of allowed size going against the report's trend.
+    Flip { trend: Trend, from: i32, to: i32 },
+    /// Adjacent levels differing by an amount outside `steps`.
+    Step {
+        from: i32,
+        to: i32,
+        steps: RangeInclusive<u32>,
+    },
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Flip { trend, from, to } => {
+                write!(f, "{from} -> {to} breaks the {trend} trend")
+            }
+            Reason::Step { from, to, steps } => {
+                write!(
+                    f,
+                    "{from} -> {to} changes by {}, outside {steps:?}",
+                    to.abs_diff(*from)
+                )
+            }
+        }
+    }
+}
+
+/// Where a report first breaks the rules: `index` is the level that ends
+/// the offending step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub index: usize,
+    pub reason: Reason,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub trend: Option<Trend>,
+    pub failure: Option<Failure>,
+    /// The indices the dampener removes to make an unsafe report safe.
+    pub fix: Option<Vec<usize>>,
+}
+
+impl Explanation {
+    pub fn is_safe(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Checks `report` against the step sizes `dampener` allows and, if it
+/// is unsafe, asks `dampener` for a fix.
+pub fn explain(report: &[i32], dampener: &Dampener) -> Explanation {
+    let (trend, failure) = check(report.iter().copied().enumerate(), &dampener.steps);
+    let fix = failure.as_ref().and_then(|_| dampener.removals(report));
+
+    Explanation {
+        trend,
+        failure,
+        fix,
+    }
+}
+
+/// Checks `(index, level)` pairs in order, so levels can be left out
+/// without copying the report.
+pub(crate) fn check(
+    mut levels: impl Iterator<Item = (usize, i32)>,
+    steps: &RangeInclusive<u32>,
+) -> (Option<Trend>, Option<Failure>) {
+    let mut trend = None;
+    let Some((_, mut previous)) = levels.next() else {
+        return (trend, None);
+    };
+
+    for (index, level) in levels {
+        let reason = if !steps.contains(&level.abs_diff(previous)) {
+            Some(Reason::Step {
+                from: previous,
+                to: level,
+                steps: steps.clone(),
+            })
+        } else {
+            let step = if level > previous {
+                Trend::Increasing
+            } else {
+                Trend::Decreasing
+            };
+            match *trend.get_or_insert(step) {
+                expected if expected != step => Some(Reason::Flip {
+                    trend: expected,
+                    from: previous,
+                    to: level,
+                }),
+                _ => None,
+            }
+        };
+
+        if let Some(reason) = reason {
+            return (trend, Some(Failure { index, reason }));
+        }
+        previous = level;
+    }
+
+    (trend, None)
+}
+
+/// One line per report for auditing: the levels with the offending one in
+/// brackets, followed by the verdict.
+pub fn annotate(report: &[i32], explanation: &Explanation) -> String {
+    let levels = report
+        .iter()
+        .enumerate()
+        .map(|(index, level)| match &explanation.failure {
+            Some(failure) if failure.index == index => format!("[{level}]"),
+            _ => level.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let verdict = match (&explanation.failure, &explanation.fix) {
+        (None, _) => match explanation.trend {
+            Some(trend) => format!("safe, {trend}"),
+            None => "safe".to_string(),
+        },
+        (Some(failure), fix) => {
+            let fix = match fix.as_deref() {
+                Some([index]) => format!("removing index {index} ({}) fixes it", report[*index]),
+                Some(indices) => {
+                    let removed: Vec<String> = indices
+                        .iter()
+                        .map(|&index| format!("{index} ({})", report[index]))
+                        .collect();
+                    format!("removing indices {} fixes it", removed.join(", "))
+                }
+                None => "the dampener cannot fix it".to_string(),
+            };
+            format!(
+                "unsafe at index {}: {}; {fix}",
+                failure.index, failure.reason
+            )
+        }
+    };
+
+    format!("{levels}  =>  {verdict}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain() {
+        let dampener = Dampener::default();
+        let safe = explain(&[7, 6, 4, 2, 1], &dampener);
+        assert!(safe.is_safe());
+        assert_eq!(safe.trend, Some(Trend::Decreasing));
+
+        let step = explain(&[1, 2, 7, 8, 9], &dampener);
+        assert_eq!(
+            step.failure,
+            Some(Failure {
+                index: 2,
+                reason: Reason::Step {
+                    from: 2,
+                    to: 7,
+                    steps: 1..=3
+                }
+            })
+        );
+        assert_eq!(step.fix, None);
+
+        let flip = explain(&[1, 3, 2, 4, 5], &dampener);
+        assert_eq!(
+            flip.failure,
+            Some(Failure {
+                index: 2,
+                reason: Reason::Flip {
+                    trend: Trend::Increasing,
+                    from: 3,
+                    to: 2
+                }
+            })
+        );
+        assert_eq!(flip.fix, Some(vec![2]));
+
+        assert_eq!(explain(&[8, 6, 4, 4, 1], &dampener).fix, Some(vec![3]));
+        assert!(explain(&[], &dampener).is_safe());
+
+        let wide = Dampener {
+            steps: 1..=8,
+            max_removals: 2,
+        };
+        assert!(explain(&[1, 2, 7, 8, 9], &wide).is_safe());
+        let explanation = explain(&[1, 20, 2, 30, 3], &wide);
+        assert_eq!(
+            explanation.failure.unwrap().reason.to_string(),
+            "1 -> 20 changes by 19, outside 1..=8"
+        );
+        assert_eq!(explanation.fix, Some(vec![1, 3]));
+    }
+
+    #[test]
+    fn test_annotate() {
+        let report = [1, 3, 2, 4, 5];
+        assert_eq!(
+            annotate(&report, &explain(&report, &Dampener::default())),
+            "1 3 [2] 4 5  =>  unsafe at index 2: 3 -> 2 breaks the increasing trend; \
+             removing index 2 (2) fixes it"
+        );
+    }
+}
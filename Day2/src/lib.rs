@@ -0,0 +1,66 @@
+// Day 2: Red-Nosed Reports
+// solution
+
+use std::fmt::Display;
+
+use util::{ParseError, Solution};
+
+pub mod dampener;
+pub mod explain;
+pub mod report;
+
+pub use dampener::Dampener;
+pub use explain::{explain, Explanation};
+pub use report::{Policy, Report};
+
+pub struct RedNosedReports;
+
+const DAY: u8 = RedNosedReports::DAY;
+
+impl Solution for RedNosedReports {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Report>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part1(reports))
+    }
+
+    fn part2(reports: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part2(reports))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Report>, ParseError> {
+    report::parse_with(input, Policy::Reject)
+}
+
+pub fn part1(reports: &[Report]) -> usize {
+    reports
+        .iter()
+        .filter_map(Report::levels)
+        .filter(|&report| is_safe(report))
+        .count()
+}
+
+pub fn part2(reports: &[Report]) -> usize {
+    reports
+        .iter()
+        .filter_map(Report::levels)
+        .filter(|&report| can_be_made_safe(report))
+        .count()
+}
+
+pub fn is_safe(report: &[i32]) -> bool {
+    Dampener::default().is_safe(report)
+}
+
+pub fn can_be_made_safe(report: &[i32]) -> bool {
+    Dampener::default().removals(report).is_some()
+}
@@ -0,0 +1,140 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use util::ParseError;
+
+use crate::DAY;
+
+/// What to do with a line that is not a list of levels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Policy {
+    /// Fail the whole parse.
+    #[default]
+    Reject,
+    /// Leave the line out.
+    Skip,
+    /// Keep the line as an [`Report::Invalid`] report, which is never safe.
+    CountUnsafe,
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(Policy::Reject),
+            "skip" => Ok(Policy::Skip),
+            "unsafe" => Ok(Policy::CountUnsafe),
+            _ => Err(format!("`{s}` is not a policy; use reject, skip or unsafe")),
+        }
+    }
+}
+
+impl Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Policy::Reject => write!(f, "reject"),
+            Policy::Skip => write!(f, "skip"),
+            Policy::CountUnsafe => write!(f, "unsafe"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Report {
+    /// The levels read from input line `line`, counted from 1.
+    Levels { line: usize, levels: Vec<i32> },
+    /// A line that failed validation, kept under [`Policy::CountUnsafe`].
+    Invalid(ParseError),
+}
+
+impl Report {
+    pub fn levels(&self) -> Option<&[i32]> {
+        match self {
+            Report::Levels { levels, .. } => Some(levels),
+            Report::Invalid(_) => None,
+        }
+    }
+
+    /// The input line the report came from, counted from 1.
+    pub fn line(&self) -> usize {
+        match self {
+            Report::Levels { line, .. } => *line,
+            Report::Invalid(e) => e.line,
+        }
+    }
+}
+
+/// Parses one report per line, handling lines that are empty or hold
+/// anything but integers according to `policy`.
+pub fn parse_with(input: &str, policy: Policy) -> Result<Vec<Report>, ParseError> {
+    let mut reports = Vec::new();
+    for (number, line) in input.lines().enumerate() {
+        match (levels(input, line), policy) {
+            (Ok(levels), _) => reports.push(Report::Levels {
+                line: number + 1,
+                levels,
+            }),
+            (Err(e), Policy::Reject) => return Err(e),
+            (Err(_), Policy::Skip) => {}
+            (Err(e), Policy::CountUnsafe) => reports.push(Report::Invalid(e)),
+        }
+    }
+    Ok(reports)
+}
+
+fn levels(input: &str, line: &str) -> Result<Vec<i32>, ParseError> {
+    if line.trim().is_empty() {
+        return Err(ParseError::at_token(DAY, input, line, "empty report"));
+    }
+
+    line.split_whitespace()
+        .map(|level| {
+            level.parse::<i32>().map_err(|e| {
+                let message = format!("`{level}` is not a level: {e}");
+                ParseError::at_token(DAY, input, level, message)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "7 6 4 2 1\n\n1 x 3\n1 3 6 7 9\n";
+
+    #[test]
+    fn test_policies() {
+        let error = parse_with(INPUT, Policy::Reject).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "empty report"));
+
+        let skipped = parse_with(INPUT, Policy::Skip).unwrap();
+        assert_eq!(
+            skipped,
+            [
+                Report::Levels {
+                    line: 1,
+                    levels: vec![7, 6, 4, 2, 1]
+                },
+                Report::Levels {
+                    line: 4,
+                    levels: vec![1, 3, 6, 7, 9]
+                }
+            ]
+        );
+
+        let kept = parse_with(INPUT, Policy::CountUnsafe).unwrap();
+        assert_eq!(kept.len(), 4);
+        let Report::Invalid(error) = &kept[2] else {
+            panic!("expected an invalid report");
+        };
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(
+            kept.iter().map(Report::line).collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(crate::part1(&kept), 2);
+        assert!(crate::is_safe(&[]) && crate::is_safe(&[4]));
+    }
+}
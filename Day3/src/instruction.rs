@@ -0,0 +1,68 @@
+/// What an instruction does to the machine running the program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Effect {
+    Enable,
+    Disable,
+    /// Add to the total while enabled.
+    Add(i64),
+}
+
+/// An instruction written `name(a,b,...)`, taking `arity` arguments of one
+/// to three digits. Implement this to teach the lexer a new instruction.
+pub trait Operation: Sync {
+    fn name(&self) -> &'static str;
+    fn arity(&self) -> usize;
+    /// Called with exactly `arity` arguments.
+    fn effect(&self, args: &[u32]) -> Effect;
+}
+
+pub struct Mul;
+pub struct Do;
+pub struct Dont;
+
+impl Operation for Mul {
+    fn name(&self) -> &'static str {
+        "mul"
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn effect(&self, args: &[u32]) -> Effect {
+        Effect::Add(i64::from(args[0]) * i64::from(args[1]))
+    }
+}
+
+impl Operation for Do {
+    fn name(&self) -> &'static str {
+        "do"
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn effect(&self, _: &[u32]) -> Effect {
+        Effect::Enable
+    }
+}
+
+impl Operation for Dont {
+    fn name(&self) -> &'static str {
+        "don't"
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn effect(&self, _: &[u32]) -> Effect {
+        Effect::Disable
+    }
+}
+
+/// The instructions part 1 looks for.
+pub const MULTIPLY: &[&dyn Operation] = &[&Mul];
+/// The instructions part 2 looks for.
+pub const CONDITIONAL: &[&dyn Operation] = &[&Mul, &Do, &Dont];
@@ -0,0 +1,184 @@
+use std::io::{self, Read};
+
+use crate::instruction::{Effect, Operation};
+use crate::lexer::{Instruction, Scanner, Token};
+use crate::total::{Arithmetic, Overflow, Total};
+
+/// How many bytes [`stream`] reads at a time by default.
+pub const CHUNK_LEN: usize = 64 * 1024;
+
+/// Runs instructions in order, adding up the enabled ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Machine<T> {
+    pub enabled: bool,
+    pub total: T,
+    pub arithmetic: Arithmetic,
+}
+
+impl<T: Total> Machine<T> {
+    pub fn new(arithmetic: Arithmetic) -> Self {
+        Self {
+            enabled: true,
+            total: T::zero(),
+            arithmetic,
+        }
+    }
+
+    pub fn execute(
+        &mut self,
+        ops: &[&dyn Operation],
+        instruction: &Instruction,
+    ) -> Result<(), Overflow> {
+        let op = ops[instruction.op];
+        match op.effect(&instruction.args) {
+            Effect::Enable => self.enabled = true,
+            Effect::Disable => self.enabled = false,
+            Effect::Add(value) if self.enabled => {
+                self.total = match self.arithmetic {
+                    Arithmetic::Wrapping => self.total.wrapping_add(value),
+                    Arithmetic::Checked => {
+                        self.total.checked_add(value).ok_or_else(|| Overflow {
+                            width: T::NAME,
+                            instruction: written(op, &instruction.args),
+                            span: instruction.span.clone(),
+                        })?
+                    }
+                };
+            }
+            Effect::Add(_) => {}
+        }
+        Ok(())
+    }
+}
+
+fn written(op: &dyn Operation, args: &[u32]) -> String {
+    let args: Vec<String> = args.iter().map(u32::to_string).collect();
+    format!("{}({})", op.name(), args.join(","))
+}
+
+/// A machine running memory as it arrives. The scanner and machine state,
+/// including whether instructions are enabled, carry over between chunks.
+/// Once the total overflows, the rest of the input is skipped.
+pub struct Program<'a, T> {
+    ops: &'a [&'a dyn Operation],
+    scanner: Scanner<'a>,
+    machine: Machine<T>,
+    overflow: Option<Overflow>,
+}
+
+impl<'a, T: Total> Program<'a, T> {
+    pub fn new(ops: &'a [&'a dyn Operation], arithmetic: Arithmetic) -> Self {
+        Self {
+            ops,
+            scanner: Scanner::new(ops),
+            machine: Machine::new(arithmetic),
+            overflow: None,
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) {
+        if self.overflow.is_some() {
+            return;
+        }
+        let (ops, machine, overflow) = (self.ops, &mut self.machine, &mut self.overflow);
+        self.scanner
+            .feed(bytes, &mut |token| execute(ops, machine, overflow, token));
+    }
+
+    /// Ends the input, running anything the scanner still held back.
+    pub fn finish(&mut self) -> Result<T, Overflow> {
+        if self.overflow.is_none() {
+            let (ops, machine, overflow) = (self.ops, &mut self.machine, &mut self.overflow);
+            self.scanner
+                .finish(&mut |token| execute(ops, machine, overflow, token));
+        }
+        match &self.overflow {
+            Some(overflow) => Err(overflow.clone()),
+            None => Ok(self.machine.total.clone()),
+        }
+    }
+}
+
+fn execute<T: Total>(
+    ops: &[&dyn Operation],
+    machine: &mut Machine<T>,
+    overflow: &mut Option<Overflow>,
+    token: Token,
+) {
+    if let (Token::Instruction(instruction), None) = (token, &overflow) {
+        *overflow = machine.execute(ops, &instruction).err();
+    }
+}
+
+/// Runs every instruction of `ops` found in `memory`, ignoring near misses.
+pub fn run<T: Total>(
+    memory: &str,
+    ops: &[&dyn Operation],
+    arithmetic: Arithmetic,
+) -> Result<T, Overflow> {
+    let mut program = Program::new(ops, arithmetic);
+    program.feed(memory.as_bytes());
+    program.finish()
+}
+
+/// Feeds everything in `reader` to each of `programs`, `chunk_len` bytes
+/// at a time. Finish the programs afterwards for their totals.
+pub fn stream<T: Total>(
+    mut reader: impl Read,
+    programs: &mut [Program<'_, T>],
+    chunk_len: usize,
+) -> io::Result<()> {
+    let mut buffer = vec![0; chunk_len.max(1)];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        for program in programs.iter_mut() {
+            program.feed(&buffer[..read]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use super::*;
+    use crate::instruction::{CONDITIONAL, MULTIPLY};
+
+    #[test]
+    fn test_chunk_boundaries() {
+        let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        for chunk_len in 1..=memory.len() {
+            let mut programs = [
+                Program::new(MULTIPLY, Arithmetic::Checked),
+                Program::new(CONDITIONAL, Arithmetic::Checked),
+            ];
+            stream(memory.as_bytes(), &mut programs, chunk_len).unwrap();
+            let [part1, part2] = programs.map(|mut program| program.finish());
+            assert_eq!(
+                (part1, part2),
+                (Ok(161i64), Ok(48)),
+                "chunks of {chunk_len}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let memory = "mul(999,999)".repeat(2200);
+        let overflow = run::<i32>(&memory, MULTIPLY, Arithmetic::Checked).unwrap_err();
+        assert_eq!(overflow.instruction, "mul(999,999)");
+        assert_eq!(overflow.span, 2151 * 12..2152 * 12);
+
+        let wrapped = run::<i32>(&memory, MULTIPLY, Arithmetic::Wrapping).unwrap();
+        assert_eq!(i64::from(wrapped), 2200 * 998_001 - (1i64 << 32));
+        assert_eq!(
+            run::<BigInt>(&memory, MULTIPLY, Arithmetic::Checked),
+            Ok(BigInt::from(2200 * 998_001i64))
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! A lexer for corrupted memory. Bytes are fed in one at a time and only
+//! the candidate instruction being read is buffered, so input can arrive in
+//! pieces of any size. Like a regex search, a candidate that falls through
+//! is retried from its second byte.
+
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+use crate::instruction::Operation;
+
+/// A well-formed instruction; `op` indexes the instruction set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: usize,
+    pub args: Vec<u32>,
+    pub span: Range<usize>,
+}
+
+/// Text that starts like an instruction, `name(`, but is not one. The span
+/// runs up to and including the byte that gave it away.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NearMiss {
+    pub op: usize,
+    pub span: Range<usize>,
+    pub miss: Miss,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Miss {
+    TooManyDigits,
+    Unexpected { expected: &'static str, found: u8 },
+    EndOfInput,
+}
+
+impl Display for Miss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Miss::TooManyDigits => write!(f, "an argument has more than 3 digits"),
+            Miss::Unexpected { expected, found } => {
+                write!(f, "expected {expected}, found `{}`", found.escape_ascii())
+            }
+            Miss::EndOfInput => write!(f, "the input ends mid-instruction"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    Instruction(Instruction),
+    NearMiss(NearMiss),
+}
+
+impl Token {
+    pub fn span(&self) -> &Range<usize> {
+        match self {
+            Token::Instruction(instruction) => &instruction.span,
+            Token::NearMiss(near_miss) => &near_miss.span,
+        }
+    }
+}
+
+/// Every instruction and near miss in `input`, in order.
+pub fn tokens(input: &str, ops: &[&dyn Operation]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut scanner = Scanner::new(ops);
+    scanner.feed(input.as_bytes(), &mut |token| tokens.push(token));
+    scanner.finish(&mut |token| tokens.push(token));
+    tokens
+}
+
+pub struct Scanner<'a> {
+    ops: &'a [&'a dyn Operation],
+    /// The candidate being read.
+    pending: Vec<u8>,
+    /// Where `pending` starts in the whole input.
+    offset: usize,
+}
+
+enum Progress {
+    Partial,
+    Done(Vec<u32>),
+    Miss(usize, Miss),
+    NoMatch,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(ops: &'a [&'a dyn Operation]) -> Self {
+        Self {
+            ops,
+            pending: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8], emit: &mut impl FnMut(Token)) {
+        for &byte in bytes {
+            self.push(byte, emit);
+        }
+    }
+
+    /// Reports a candidate cut off by the end of the input, then rescans
+    /// what followed its first byte.
+    pub fn finish(&mut self, emit: &mut impl FnMut(Token)) {
+        while !self.pending.is_empty() {
+            if let Some(op) = self.ops.iter().position(|op| {
+                matches!(progress(*op, &self.pending), Progress::Partial)
+                    && self.pending.len() > op.name().len()
+            }) {
+                emit(Token::NearMiss(NearMiss {
+                    op,
+                    span: self.offset..self.offset + self.pending.len(),
+                    miss: Miss::EndOfInput,
+                }));
+            }
+            self.retry(emit);
+        }
+    }
+
+    fn push(&mut self, byte: u8, emit: &mut impl FnMut(Token)) {
+        self.pending.push(byte);
+
+        let mut partial = false;
+        let mut missed = None;
+        for (op, operation) in self.ops.iter().enumerate() {
+            match progress(*operation, &self.pending) {
+                Progress::Done(args) => {
+                    let end = self.offset + self.pending.len();
+                    emit(Token::Instruction(Instruction {
+                        op,
+                        args,
+                        span: self.offset..end,
+                    }));
+                    self.pending.clear();
+                    self.offset = end;
+                    return;
+                }
+                Progress::Partial => partial = true,
+                Progress::Miss(len, miss) => {
+                    missed.get_or_insert((op, len, miss));
+                }
+                Progress::NoMatch => {}
+            }
+        }
+        if partial {
+            return;
+        }
+
+        if let Some((op, len, miss)) = missed {
+            emit(Token::NearMiss(NearMiss {
+                op,
+                span: self.offset..self.offset + len,
+                miss,
+            }));
+        }
+        self.retry(emit);
+    }
+
+    /// Drops the first pending byte and feeds the rest back in.
+    fn retry(&mut self, emit: &mut impl FnMut(Token)) {
+        let rest: Vec<u8> = self.pending.drain(1..).collect();
+        self.pending.clear();
+        self.offset += 1;
+        for byte in rest {
+            self.push(byte, emit);
+        }
+    }
+}
+
+/// How far `buf` gets as the start of an `op` instruction.
+fn progress(op: &dyn Operation, buf: &[u8]) -> Progress {
+    let name = op.name().as_bytes();
+    for (i, &byte) in buf.iter().take(name.len() + 1).enumerate() {
+        if byte != name.get(i).copied().unwrap_or(b'(') {
+            return Progress::NoMatch;
+        }
+    }
+
+    let mut i = name.len() + 1;
+    let mut args = Vec::with_capacity(op.arity());
+    let expect = |i: usize, byte: u8, expected| match buf.get(i) {
+        None => Err(Progress::Partial),
+        Some(&found) if found != byte => {
+            Err(Progress::Miss(i + 1, Miss::Unexpected { expected, found }))
+        }
+        Some(_) => Ok(i + 1),
+    };
+
+    for k in 0..op.arity() {
+        if k > 0 {
+            i = match expect(i, b',', "`,`") {
+                Ok(i) => i,
+                Err(progress) => return progress,
+            };
+        }
+
+        let start = i;
+        while buf.get(i).is_some_and(u8::is_ascii_digit) {
+            if i - start == 3 {
+                return Progress::Miss(i + 1, Miss::TooManyDigits);
+            }
+            i += 1;
+        }
+        match buf.get(i) {
+            None => return Progress::Partial,
+            Some(&found) if i == start => {
+                return Progress::Miss(
+                    i + 1,
+                    Miss::Unexpected {
+                        expected: "a digit",
+                        found,
+                    },
+                )
+            }
+            Some(_) => args.push(
+                buf[start..i]
+                    .iter()
+                    .fold(0, |n, digit| n * 10 + u32::from(digit - b'0')),
+            ),
+        }
+    }
+
+    match expect(i, b')', "`)`") {
+        Ok(_) => Progress::Done(args),
+        Err(progress) => progress,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::CONDITIONAL;
+
+    #[test]
+    fn test_spans() {
+        let input = "xmul(2,4)&mul(1, 2)don't()mul(1234,5)?mul(8,5))do(";
+        let tokens = tokens(input, CONDITIONAL);
+        let spans: Vec<_> = tokens
+            .iter()
+            .map(|token| &input[token.span().clone()])
+            .collect();
+        assert_eq!(
+            spans,
+            ["mul(2,4)", "mul(1, ", "don't()", "mul(1234", "mul(8,5)", "do("]
+        );
+
+        assert_eq!(
+            tokens[0],
+            Token::Instruction(Instruction {
+                op: 0,
+                args: vec![2, 4],
+                span: 1..9
+            })
+        );
+        let misses: Vec<_> = tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::NearMiss(near_miss) => Some(near_miss.miss),
+                Token::Instruction(_) => None,
+            })
+            .collect();
+        assert_eq!(
+            misses,
+            [
+                Miss::Unexpected {
+                    expected: "a digit",
+                    found: b' '
+                },
+                Miss::TooManyDigits,
+                Miss::EndOfInput
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retry() {
+        let spans: Vec<_> = tokens("mul(mul(2,3)mumul(4,5)", CONDITIONAL)
+            .iter()
+            .map(|token| token.span().clone())
+            .collect();
+        assert_eq!(spans, [0..5, 4..12, 14..22]);
+    }
+}
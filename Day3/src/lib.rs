@@ -0,0 +1,49 @@
+// Day 3: Mull It Over
+
+use std::convert::Infallible;
+use std::fmt::Display;
+
+use num_bigint::BigInt;
+use util::Solution;
+
+pub mod instruction;
+pub mod interpreter;
+pub mod lexer;
+pub mod total;
+
+use instruction::{Operation, CONDITIONAL, MULTIPLY};
+use total::Arithmetic;
+
+pub struct MullItOver;
+
+impl Solution for MullItOver {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = String;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(memory: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part1(memory))
+    }
+
+    fn part2(memory: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part2(memory))
+    }
+}
+
+pub fn part1(input: &str) -> BigInt {
+    total(input, MULTIPLY)
+}
+
+pub fn part2(input: &str) -> BigInt {
+    total(input, CONDITIONAL)
+}
+
+fn total(input: &str, ops: &[&dyn Operation]) -> BigInt {
+    interpreter::run(input, ops, Arithmetic::Checked).expect("a bigint total cannot overflow")
+}
@@ -0,0 +1,84 @@
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+use num_bigint::BigInt;
+
+/// A number type the interpreter can add up products in.
+pub trait Total: Clone + Display {
+    /// Shown in overflow reports.
+    const NAME: &'static str;
+
+    fn zero() -> Self;
+    fn checked_add(&self, value: i64) -> Option<Self>;
+    fn wrapping_add(&self, value: i64) -> Self;
+}
+
+macro_rules! total {
+    ($type:ty, $name:literal) => {
+        impl Total for $type {
+            const NAME: &'static str = $name;
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn checked_add(&self, value: i64) -> Option<Self> {
+                <$type>::checked_add(*self, value.try_into().ok()?)
+            }
+
+            fn wrapping_add(&self, value: i64) -> Self {
+                <$type>::wrapping_add(*self, value as $type)
+            }
+        }
+    };
+}
+
+total!(i32, "i32");
+total!(i64, "i64");
+total!(i128, "i128");
+
+impl Total for BigInt {
+    const NAME: &'static str = "bigint";
+
+    fn zero() -> Self {
+        BigInt::ZERO
+    }
+
+    fn checked_add(&self, value: i64) -> Option<Self> {
+        Some(self + value)
+    }
+
+    fn wrapping_add(&self, value: i64) -> Self {
+        self + value
+    }
+}
+
+/// What to do when the total no longer fits its type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Stop at the instruction that overflows and report it.
+    #[default]
+    Checked,
+    /// Wrap around silently.
+    Wrapping,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    pub width: &'static str,
+    /// The instruction that overflowed, as written.
+    pub instruction: String,
+    pub span: Range<usize>,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the total overflows {} at `{}`, bytes {}..{} (try a wider number type)",
+            self.width, self.instruction, self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use util::grid::Grid;
+use util::{ParseError, Solution};
+
+pub mod loader;
+pub mod render;
+pub mod search;
+pub mod stencil;
+
+pub use loader::Ragged;
+pub use render::Style;
+pub use search::{Direction, Match, WordSearch, Wrap};
+pub use stencil::{Stencil, StencilMatch};
+
+pub struct CeresSearch;
+
+const DAY: u8 = CeresSearch::DAY;
+
+impl Solution for CeresSearch {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = Grid<char>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        loader::load(input, Ragged::Reject)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(count_matches(map, "XMAS"))
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(count_cross_pattern(map))
+    }
+}
+
+/// Occurrences of `word` read in any of the eight directions.
+pub fn count_matches(map: &Grid<char>, word: &str) -> usize {
+    WordSearch::new([word]).find(map, Wrap::No).len()
+}
+
+/// Places the two crossed `MAS`es of [`stencil::X_MAS`] appear.
+pub fn count_cross_pattern(map: &Grid<char>) -> usize {
+    let stencil: Stencil = stencil::X_MAS.parse().expect("the X-MAS stencil is rectangular");
+    stencil.find(map).len()
+}
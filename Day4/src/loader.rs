@@ -0,0 +1,119 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use util::grid::{Grid, GridError};
+use util::ParseError;
+
+use crate::DAY;
+
+/// What to do with rows shorter or longer than the rest.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Ragged {
+    /// Fail on the first row whose width differs from the first row's.
+    #[default]
+    Reject,
+    /// Fill every row up to the widest one with this character.
+    Pad(char),
+}
+
+impl FromStr for Ragged {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "reject" => Ok(Ragged::Reject),
+            "pad" => Ok(Ragged::Pad('.')),
+            _ => {
+                let mut fill = s.strip_prefix("pad:").unwrap_or_default().chars();
+                match (fill.next(), fill.next()) {
+                    (Some(c), None) => Ok(Ragged::Pad(c)),
+                    _ => Err(format!(
+                        "`{s}` is not a ragged-row policy; use reject, pad or pad:<character>"
+                    )),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Ragged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ragged::Reject => write!(f, "reject"),
+            Ragged::Pad('.') => write!(f, "pad"),
+            Ragged::Pad(c) => write!(f, "pad:{c}"),
+        }
+    }
+}
+
+/// Reads a letter grid with LF or CRLF line endings, ignoring blank lines
+/// at the end.
+pub fn load(input: &str, ragged: Ragged) -> Result<Grid<char>, ParseError> {
+    let mut rows: Vec<&str> = input
+        .lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+
+    let width = match ragged {
+        Ragged::Reject => rows.first().map(|row| row.chars().count()),
+        Ragged::Pad(_) => rows.iter().map(|row| row.chars().count()).max(),
+    }
+    .unwrap_or_default();
+
+    let mut cells = Vec::with_capacity(width * rows.len());
+    for (row, line) in rows.iter().enumerate() {
+        let before = cells.len();
+        cells.extend(line.chars());
+        match ragged {
+            Ragged::Pad(fill) => cells.resize(before + width, fill),
+            Ragged::Reject if cells.len() - before != width => {
+                let error = GridError::Ragged {
+                    row,
+                    expected: width,
+                    found: cells.len() - before,
+                };
+                return Err(ParseError::from_grid(DAY, input, error));
+            }
+            Ragged::Reject => {}
+        }
+    }
+
+    Ok(Grid::new(width, rows.len(), cells))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_endings() {
+        let expected: Grid<char> = "XM\nAS".parse().unwrap();
+        for input in [
+            "XM\nAS",
+            "XM\nAS\n",
+            "XM\r\nAS\r\n",
+            "XM\r\nAS\r\n\r\n\n",
+            "XM\nAS\r",
+        ] {
+            let grid = load(input, Ragged::Reject).unwrap();
+            assert_eq!(grid, expected, "{input:?}");
+            assert_eq!((grid.width(), grid.height()), (2, 2));
+        }
+        assert_eq!(load("", Ragged::Reject).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn test_ragged() {
+        let input = "XMAS\r\nXM\r\n\r\nXMASX\r\n";
+        let error = load(input, Ragged::Reject).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "row 2 has 2 cells, expected 4");
+
+        let grid = load(input, "pad:_".parse().unwrap()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+        assert_eq!(grid.to_string(), "XMAS_\nXM___\n_____\nXMASX");
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use util::grid::Grid;
+use util::point::Point;
+
+/// How to show which letters belong to a match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
+    /// Matched letters in bold colour, for terminals.
+    Ansi,
+    /// Matched letters only, with `.` everywhere else.
+    Plain,
+    /// A `<pre>` block with matched letters in `<mark>`.
+    Html,
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Style::Ansi),
+            "plain" => Ok(Style::Plain),
+            "html" => Ok(Style::Html),
+            _ => Err(format!("`{s}` is not a style; use ansi, plain or html")),
+        }
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Style::Ansi => write!(f, "ansi"),
+            Style::Plain => write!(f, "plain"),
+            Style::Html => write!(f, "html"),
+        }
+    }
+}
+
+/// Draws `grid` with the `marked` cells highlighted. Points outside the
+/// grid are ignored.
+pub fn render(grid: &Grid<char>, marked: impl IntoIterator<Item = Point>, style: Style) -> String {
+    let mut highlight = grid.map(|_| false);
+    for point in marked {
+        if let Some(cell) = highlight.get_mut(point) {
+            *cell = true;
+        }
+    }
+
+    let (open, close) = match style {
+        Style::Ansi => ("\x1b[1;31m", "\x1b[0m"),
+        Style::Plain => ("", ""),
+        Style::Html => ("<mark>", "</mark>"),
+    };
+
+    let mut out = String::new();
+    if style == Style::Html {
+        out.push_str("<pre class=\"word-search\">\n");
+    }
+    for y in 0..grid.height() as i32 {
+        let mut inside = false;
+        for x in 0..grid.width() as i32 {
+            let point = Point::new(x, y);
+            if highlight[point] != inside {
+                inside = highlight[point];
+                out.push_str(if inside { open } else { close });
+            }
+            match (style, grid[point]) {
+                (Style::Plain, _) if !inside => out.push('.'),
+                (Style::Html, '<') => out.push_str("&lt;"),
+                (Style::Html, '>') => out.push_str("&gt;"),
+                (Style::Html, '&') => out.push_str("&amp;"),
+                (_, c) => out.push(c),
+            }
+        }
+        if inside {
+            out.push_str(close);
+        }
+        out.push('\n');
+    }
+    if style == Style::Html {
+        out.push_str("</pre>\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styles() {
+        let grid: Grid<char> = "AB<\nCDE".parse().unwrap();
+        let marked = [
+            Point::new(0, 0),
+            Point::new(1, 0),
+            Point::new(2, 1),
+            Point::new(9, 9),
+        ];
+
+        assert_eq!(render(&grid, marked, Style::Plain), "AB.\n..E\n");
+        assert_eq!(
+            render(&grid, marked, Style::Ansi),
+            "\x1b[1;31mAB\x1b[0m<\nCD\x1b[1;31mE\x1b[0m\n"
+        );
+        assert_eq!(
+            render(&grid, marked, Style::Html),
+            "<pre class=\"word-search\">\n<mark>AB</mark>&lt;\nCD<mark>E</mark>\n</pre>\n"
+        );
+    }
+}
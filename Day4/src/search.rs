@@ -0,0 +1,314 @@
+//! Finds any number of words in a letter grid in one pass per direction.
+//!
+//! The words are compiled into an Aho–Corasick automaton, and every line of
+//! the grid in each of the eight directions is fed through it once, so the
+//! cost does not grow with the number of words searched for.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use util::grid::Grid;
+use util::point::Point;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// One step in this direction, with y growing downwards.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::NorthEast => Point::new(1, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(0, 1),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::North => "N",
+            Direction::NorthEast => "NE",
+            Direction::East => "E",
+            Direction::SouthEast => "SE",
+            Direction::South => "S",
+            Direction::SouthWest => "SW",
+            Direction::West => "W",
+            Direction::NorthWest => "NW",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// One occurrence of a word; `word` indexes the searched words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Match {
+    pub word: usize,
+    pub start: Point,
+    pub direction: Direction,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Wrap {
+    /// Words stop at the edges.
+    #[default]
+    No,
+    /// Words running off an edge continue from the opposite one.
+    Around,
+}
+
+pub struct WordSearch {
+    words: Vec<Vec<char>>,
+    nodes: Vec<Node>,
+}
+
+#[derive(Default)]
+struct Node {
+    next: HashMap<char, usize>,
+    fail: usize,
+    /// The words ending here, including through fail links.
+    words: Vec<usize>,
+}
+
+impl WordSearch {
+    /// Empty words are never found.
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        let words: Vec<Vec<char>> = words
+            .into_iter()
+            .map(|word| word.as_ref().chars().collect())
+            .collect();
+
+        let mut nodes = vec![Node::default()];
+        for (index, word) in words.iter().enumerate() {
+            if word.is_empty() {
+                continue;
+            }
+            let mut node = 0;
+            for &c in word {
+                node = match nodes[node].next.get(&c) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].next.insert(c, next);
+                        next
+                    }
+                };
+            }
+            nodes[node].words.push(index);
+        }
+
+        // Fail links, breadth first so shorter suffixes are done first.
+        let mut queue: Vec<usize> = nodes[0].next.values().copied().collect();
+        let mut head = 0;
+        while let Some(&node) = queue.get(head) {
+            head += 1;
+            let edges: Vec<(char, usize)> = nodes[node]
+                .next
+                .iter()
+                .map(|(&c, &child)| (c, child))
+                .collect();
+            for (c, child) in edges {
+                let mut fail = nodes[node].fail;
+                let target = loop {
+                    if let Some(&next) = nodes[fail].next.get(&c) {
+                        break next;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = nodes[fail].fail;
+                };
+                nodes[child].fail = target;
+                let inherited = nodes[target].words.clone();
+                nodes[child].words.extend(inherited);
+                queue.push(child);
+            }
+        }
+
+        Self { words, nodes }
+    }
+
+    pub fn word(&self, index: usize) -> &[char] {
+        &self.words[index]
+    }
+
+    /// The cells `found` covers in `grid`, as searched with `wrap`.
+    pub fn cells(&self, found: &Match, grid: &Grid<char>, wrap: Wrap) -> Vec<Point> {
+        let (width, height) = (grid.width() as i32, grid.height() as i32);
+        (0..self.words[found.word].len())
+            .map(|step| found.start + found.direction.offset() * step)
+            .map(|point| match wrap {
+                Wrap::No => point,
+                Wrap::Around => {
+                    Point::new(point.x().rem_euclid(width), point.y().rem_euclid(height))
+                }
+            })
+            .collect()
+    }
+
+    fn step(&self, mut node: usize, c: char) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[node].next.get(&c) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// Every occurrence of every word, line by line in each direction.
+    /// A palindrome is found once in each direction it reads in.
+    pub fn find(&self, grid: &Grid<char>, wrap: Wrap) -> Vec<Match> {
+        let longest = self.words.iter().map(Vec::len).max().unwrap_or_default();
+        let mut matches = Vec::new();
+
+        for direction in Direction::ALL {
+            for line in lines(grid, direction.offset(), wrap) {
+                // A cycle is read on past its end so words can wrap around.
+                let n = line.len();
+                let len = match wrap {
+                    Wrap::No => n,
+                    Wrap::Around => n + longest.saturating_sub(1),
+                };
+
+                let mut node = 0;
+                for i in 0..len {
+                    node = self.step(node, grid[line[i % n]]);
+                    for &word in &self.nodes[node].words {
+                        let length = self.words[word].len();
+                        let start = i + 1 - length;
+                        if start < n && length <= n {
+                            matches.push(Match {
+                                word,
+                                start: line[start],
+                                direction,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+/// The lines of `grid` running in `step`: rays from the edge, or with
+/// wraparound, the cycles `step` goes round.
+fn lines(grid: &Grid<char>, step: Point, wrap: Wrap) -> Vec<Vec<Point>> {
+    match wrap {
+        Wrap::No => grid
+            .points()
+            .filter(|&point| !grid.contains(point - step))
+            .map(|start| grid.walk(start, step).map(|(point, _)| point).collect())
+            .collect(),
+        Wrap::Around => {
+            let (width, height) = (grid.width() as i32, grid.height() as i32);
+            let next = |point: Point| {
+                Point::new(
+                    (point.x() + step.x()).rem_euclid(width),
+                    (point.y() + step.y()).rem_euclid(height),
+                )
+            };
+
+            let mut seen = grid.map(|_| false);
+            let mut cycles = Vec::new();
+            for start in grid.points() {
+                if seen[start] {
+                    continue;
+                }
+                let mut cycle = vec![start];
+                seen[start] = true;
+                let mut point = next(start);
+                while point != start {
+                    cycle.push(point);
+                    seen[point] = true;
+                    point = next(point);
+                }
+                cycles.push(cycle);
+            }
+            cycles
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let grid: Grid<char> = "ABC\nDEF\nGHI\n".parse().unwrap();
+        let search = WordSearch::new(["EI", "CEG", "BEH", "HEB", "FD", "", "E"]);
+
+        let matches = search.find(&grid, Wrap::No);
+        let found = |word| matches.iter().filter(move |m| m.word == word);
+        assert_eq!(
+            found(0).collect::<Vec<_>>(),
+            [&Match {
+                word: 0,
+                start: Point::new(1, 1),
+                direction: Direction::SouthEast
+            }]
+        );
+        assert_eq!(found(1).next().unwrap().direction, Direction::SouthWest);
+        assert_eq!(found(2).count(), 1);
+        assert_eq!(found(3).next().unwrap().start, Point::new(1, 2));
+        assert_eq!(found(4).count(), 0);
+        assert_eq!(found(5).count(), 0);
+        assert_eq!(found(6).count(), 8);
+
+        let wrapped = search.find(&grid, Wrap::Around);
+        let fd: Vec<_> = wrapped.iter().filter(|m| m.word == 4).collect();
+        assert_eq!(
+            fd,
+            [&Match {
+                word: 4,
+                start: Point::new(2, 1),
+                direction: Direction::East
+            }]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let grid: Grid<char> = "SHESHE".parse().unwrap();
+        let search = WordSearch::new(["HE", "SHE", "HERS", "ESH"]);
+        let mut found: Vec<_> = search
+            .find(&grid, Wrap::No)
+            .into_iter()
+            .filter(|m| m.direction == Direction::East)
+            .map(|m| (m.word, m.start.x()))
+            .collect();
+        found.sort();
+        assert_eq!(found, [(0, 1), (0, 4), (1, 0), (1, 3), (3, 2)]);
+    }
+}
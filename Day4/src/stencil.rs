@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+use util::grid::{Grid, GridError};
+use util::point::Point;
+
+/// The two crossed `MAS`es of part 2.
+pub const X_MAS: &str = "M.S/.A./M.S";
+
+/// A small shape of letters to look for in a grid, matched in every
+/// rotation and reflection. Written one row per line or with rows separated
+/// by `/`, with `.` for cells that may hold anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stencil {
+    /// The distinct orientations; the first is the shape as written.
+    variants: Vec<Grid<Option<char>>>,
+}
+
+/// Where one orientation of a stencil lies in the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StencilMatch {
+    pub variant: usize,
+    pub top_left: Point,
+}
+
+impl FromStr for Stencil {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let shape = Grid::parse(&s.replace('/', "\n"), |c| (c != '.').then_some(c))?;
+        Ok(Self::new(shape))
+    }
+}
+
+impl Stencil {
+    pub fn new(shape: Grid<Option<char>>) -> Self {
+        let mut variants = Vec::with_capacity(8);
+        let mut add = |variant: Grid<Option<char>>| {
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
+        };
+
+        let mut mirrored = reflect(&shape);
+        let mut shape = shape;
+        for _ in 0..4 {
+            let (next, next_mirrored) = (rotate(&shape), rotate(&mirrored));
+            add(shape);
+            add(mirrored);
+            (shape, mirrored) = (next, next_mirrored);
+        }
+
+        Self { variants }
+    }
+
+    pub fn variants(&self) -> &[Grid<Option<char>>] {
+        &self.variants
+    }
+
+    /// The letters, not wildcards, that `found` covers.
+    pub fn cells<'a>(&'a self, found: &'a StencilMatch) -> impl Iterator<Item = Point> + 'a {
+        self.variants[found.variant]
+            .iter()
+            .filter(|(_, cell)| cell.is_some())
+            .map(|(offset, _)| found.top_left + offset)
+    }
+
+    /// Every place any orientation fits, row by row for each orientation.
+    /// An empty stencil fits nowhere.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<StencilMatch> {
+        let mut matches = Vec::new();
+        for (variant, shape) in self.variants.iter().enumerate() {
+            if shape.width() == 0 || shape.height() == 0 {
+                continue;
+            }
+            for top_left in grid.points() {
+                let fits = shape.iter().all(|(offset, cell)| {
+                    let found = grid.get(top_left + offset);
+                    found.is_some() && cell.is_none_or(|c| found == Some(&c))
+                });
+                if fits {
+                    matches.push(StencilMatch { variant, top_left });
+                }
+            }
+        }
+        matches
+    }
+}
+
+/// Turns `shape` a quarter clockwise.
+fn rotate<T: Clone>(shape: &Grid<T>) -> Grid<T> {
+    let (width, height) = (shape.width(), shape.height());
+    let cells = (0..width as i32)
+        .flat_map(|y| (0..height as i32).map(move |x| Point::new(y, height as i32 - 1 - x)))
+        .map(|point| shape[point].clone())
+        .collect();
+    Grid::new(height, width, cells)
+}
+
+/// Mirrors `shape` left to right.
+fn reflect<T: Clone>(shape: &Grid<T>) -> Grid<T> {
+    let width = shape.width() as i32;
+    let cells = shape
+        .points()
+        .map(|point| shape[Point::new(width - 1 - point.x(), point.y())].clone())
+        .collect();
+    Grid::new(shape.width(), shape.height(), cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(X_MAS.parse::<Stencil>().unwrap().variants().len(), 4);
+        assert_eq!("ABC".parse::<Stencil>().unwrap().variants().len(), 4);
+        assert_eq!("AB/C.".parse::<Stencil>().unwrap().variants().len(), 8);
+        assert_eq!(
+            "A.A/.A./A.A".parse::<Stencil>().unwrap().variants().len(),
+            1
+        );
+        assert!("AB/C".parse::<Stencil>().is_err());
+    }
+
+    #[test]
+    fn test_find() {
+        let grid: Grid<char> = "xAB\nCBA\nyyy".parse().unwrap();
+        let stencil: Stencil = "AB".parse().unwrap();
+        let mut found: Vec<_> = stencil
+            .find(&grid)
+            .into_iter()
+            .map(|m| (m.top_left, stencil.variants()[m.variant].width()))
+            .collect();
+        found.sort_by_key(|&(point, width)| (point.y(), point.x(), width));
+        assert_eq!(
+            found,
+            [
+                (Point::new(1, 0), 1),
+                (Point::new(1, 0), 2),
+                (Point::new(2, 0), 1),
+                (Point::new(1, 1), 2)
+            ]
+        );
+    }
+}
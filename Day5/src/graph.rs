@@ -0,0 +1,175 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display};
+
+use crate::Rules;
+
+/// The ordering rules between the pages of one update, as a graph with an
+/// edge from each page to the pages that must come after it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleGraph {
+    pages: Vec<u32>,
+    after: Vec<Vec<usize>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrderError {
+    Duplicate(u32),
+    /// Pages that each must come before the next, and the last before the
+    /// first.
+    Cycle(Vec<u32>),
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderError::Duplicate(page) => {
+                write!(f, "page {page} appears more than once in the update")
+            }
+            OrderError::Cycle(pages) => {
+                write!(f, "the rules for this update form a cycle:")?;
+                for (i, page) in pages.iter().enumerate() {
+                    let next = pages[(i + 1) % pages.len()];
+                    write!(f, " {page}|{next}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OrderError {}
+
+impl RuleGraph {
+    /// Keeps only the rules between pages of `update`.
+    pub fn new(rules: &Rules, update: &[u32]) -> Result<Self, OrderError> {
+        let mut index = HashMap::with_capacity(update.len());
+        for (i, &page) in update.iter().enumerate() {
+            if index.insert(page, i).is_some() {
+                return Err(OrderError::Duplicate(page));
+            }
+        }
+
+        let after = update
+            .iter()
+            .map(|page| {
+                rules
+                    .get(page)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|later| index.get(later).copied())
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            pages: update.to_vec(),
+            after,
+        })
+    }
+
+    /// The pages in an order every rule agrees with, by Kahn's algorithm.
+    /// Pages the rules leave unordered keep their order in the update.
+    pub fn order(&self) -> Result<Vec<u32>, OrderError> {
+        let mut incoming = vec![0; self.pages.len()];
+        for &later in self.after.iter().flatten() {
+            incoming[later] += 1;
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.pages.len())
+            .filter(|&page| incoming[page] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.pages.len());
+        while let Some(page) = ready.pop_front() {
+            order.push(self.pages[page]);
+            for &later in &self.after[page] {
+                incoming[later] -= 1;
+                if incoming[later] == 0 {
+                    ready.push_back(later);
+                }
+            }
+        }
+
+        if order.len() == self.pages.len() {
+            Ok(order)
+        } else {
+            Err(OrderError::Cycle(self.cycle(&incoming)))
+        }
+    }
+
+    /// Finds a cycle among the pages Kahn's algorithm could not place.
+    /// Each of those still has an unplaced page before it, so walking
+    /// backwards from one must eventually repeat.
+    fn cycle(&self, incoming: &[usize]) -> Vec<u32> {
+        let mut before = vec![None; self.pages.len()];
+        for (page, after) in self.after.iter().enumerate() {
+            for &later in after {
+                if incoming[page] > 0 && incoming[later] > 0 {
+                    before[later] = Some(page);
+                }
+            }
+        }
+
+        let mut seen = vec![false; self.pages.len()];
+        let mut page = incoming
+            .iter()
+            .position(|&count| count > 0)
+            .expect("an unplaced page");
+        while !seen[page] {
+            seen[page] = true;
+            page = before[page].expect("an unplaced page has an unplaced page before it");
+        }
+
+        let start = page;
+        let mut cycle = vec![self.pages[start]];
+        page = before[start].expect("a page on a cycle");
+        while page != start {
+            cycle.push(self.pages[page]);
+            page = before[page].expect("a page on a cycle");
+        }
+        cycle.reverse();
+        cycle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(pairs: &[(u32, u32)]) -> Rules {
+        let mut rules = Rules::new();
+        for &(page, later) in pairs {
+            rules.entry(page).or_default().push(later);
+        }
+        rules
+    }
+
+    #[test]
+    fn test_order() {
+        let rules = rules(&[(97, 75), (75, 47), (47, 61), (61, 53), (97, 47), (13, 1)]);
+        let graph = RuleGraph::new(&rules, &[75, 97, 47, 61, 53]).unwrap();
+        assert_eq!(graph.order(), Ok(vec![97, 75, 47, 61, 53]));
+        assert_eq!(
+            RuleGraph::new(&rules, &[61, 7, 47]).unwrap().order(),
+            Ok(vec![7, 47, 61])
+        );
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        let rules = rules(&[(1, 2), (2, 3), (3, 4), (4, 2), (5, 1)]);
+        let error = RuleGraph::new(&rules, &[5, 4, 3, 2, 1])
+            .unwrap()
+            .order()
+            .unwrap_err();
+        assert_eq!(error, OrderError::Cycle(vec![2, 3, 4]));
+        assert_eq!(
+            error.to_string(),
+            "the rules for this update form a cycle: 2|3 3|4 4|2"
+        );
+
+        assert_eq!(
+            RuleGraph::new(&rules, &[1, 2, 1]),
+            Err(OrderError::Duplicate(1))
+        );
+    }
+}
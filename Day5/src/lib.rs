@@ -0,0 +1,277 @@
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    multi::{fold_many1, separated_list1},
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use util::{ParseError, Solution};
+
+pub mod graph;
+pub mod validate;
+
+pub use graph::{OrderError, RuleGraph};
+pub use validate::{Validation, ValidationReport, Violation};
+
+pub struct PrintQueue;
+
+const DAY: u8 = PrintQueue::DAY;
+
+impl Solution for PrintQueue {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = SafetyManual;
+    type Error = ManualError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse_manual(input)?)
+    }
+
+    fn part1(manual: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part_1(manual))
+    }
+
+    fn part2(manual: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part_2(manual)?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ManualError {
+    Parse(ParseError),
+    /// An out-of-order update whose rules cannot be ordered.
+    Order(OrderError),
+}
+
+impl Display for ManualError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManualError::Parse(e) => e.fmt(f),
+            ManualError::Order(e) => write!(f, "day {DAY}: an update cannot be put in order: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ManualError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ManualError::Parse(_) => None,
+            ManualError::Order(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for ManualError {
+    fn from(e: ParseError) -> Self {
+        ManualError::Parse(e)
+    }
+}
+
+impl From<OrderError> for ManualError {
+    fn from(e: OrderError) -> Self {
+        ManualError::Order(e)
+    }
+}
+
+/// Reads the ordering rules and the updates, checking only their layout.
+pub fn parse_manual(input: &str) -> Result<SafetyManual, ParseError> {
+    let (rest, (rules, updates)) = parse(input).map_err(|e| match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => ParseError::at_token(
+            DAY,
+            input,
+            e.input,
+            format!("unexpected input, expected {:?}", e.code),
+        ),
+        nom::Err::Incomplete(_) => {
+            ParseError::at_offset(DAY, input, input.len(), "unexpected end of input")
+        }
+    })?;
+    if !rest.trim().is_empty() {
+        return Err(ParseError::at_token(
+            DAY,
+            input,
+            rest.trim_start(),
+            "expected a comma-separated update",
+        ));
+    }
+
+    Ok(SafetyManual { rules, updates })
+}
+
+/// The pages each page must come before.
+pub type Rules = HashMap<u32, Vec<u32>>;
+
+#[derive(Debug)]
+pub struct SafetyManual {
+    rules: Rules,
+    updates: Vec<Vec<u32>>,
+}
+
+impl SafetyManual {
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub fn updates(&self) -> &[Vec<u32>] {
+        &self.updates
+    }
+
+    /// The rules each update breaks and its sorted order, in one pass over
+    /// the updates.
+    pub fn validate(&self) -> Vec<Validation> {
+        self.updates
+            .iter()
+            .map(|update| Validation {
+                violations: validate::violations(&self.rules, update),
+                order: self.order(update),
+            })
+            .collect()
+    }
+
+    /// `update` in an order every rule agrees with.
+    pub fn order(&self, update: &[u32]) -> Result<Vec<u32>, OrderError> {
+        RuleGraph::new(&self.rules, update)?.order()
+    }
+}
+
+pub fn part_1(manual: &SafetyManual) -> u32 {
+    manual
+        .updates
+        .iter()
+        .zip(manual.validate())
+        .filter(|(_, validation)| validation.violations.is_empty())
+        .map(|(update, _)| update[update.len() / 2])
+        .sum()
+}
+
+/// Fails on the first out-of-order update whose rules cannot be ordered.
+pub fn part_2(manual: &SafetyManual) -> Result<u32, OrderError> {
+    manual
+        .validate()
+        .into_iter()
+        .filter(|validation| !validation.violations.is_empty())
+        .map(|validation| validation.order.map(|sorted| sorted[sorted.len() / 2]))
+        .sum()
+}
+
+fn rules(input: &str) -> IResult<&str, Rules> {
+    fold_many1(
+        terminated(
+            separated_pair(complete::u32, tag("|"), complete::u32),
+            line_ending,
+        ),
+        HashMap::default,
+        |mut acc: Rules, (page, after)| {
+            acc.entry(page)
+                .and_modify(|afters| {
+                    afters.push(after);
+                })
+                .or_insert(vec![after]);
+            acc
+        },
+    )(input)
+}
+
+fn updates(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
+    separated_list1(line_ending, separated_list1(tag(","), complete::u32))(input)
+}
+
+fn parse(input: &str) -> IResult<&str, (Rules, Vec<Vec<u32>>)> {
+    let (input, parsed_rules) = terminated(rules, line_ending)(input)?;
+    let (input, parsed_updates) = updates(input)?;
+    Ok((input, (parsed_rules, parsed_updates)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() -> Result<(), ParseError> {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+        let manual = parse_manual(input)?;
+        assert_eq!(143, part_1(&manual));
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_2() -> Result<(), ParseError> {
+        let input = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+        let manual = parse_manual(input)?;
+        assert_eq!(Ok(123), part_2(&manual));
+        Ok(())
+    }
+
+    #[test]
+    fn test_inconsistent_update() -> Result<(), ParseError> {
+        let manual = parse_manual("1|2\n2|3\n3|1\n\n3,2,1\n4,5,4\n6,7\n")?;
+        assert_eq!(12, part_1(&manual));
+        assert_eq!(
+            manual.order(&manual.updates()[1]),
+            Err(OrderError::Duplicate(4))
+        );
+        assert_eq!(part_2(&manual), Err(OrderError::Cycle(vec![1, 2, 3])));
+        let Err(error) = PrintQueue::part2(&manual) else {
+            panic!("expected part 2 to fail");
+        };
+        assert!(matches!(error, ManualError::Order(OrderError::Cycle(_))));
+        Ok(())
+    }
+}
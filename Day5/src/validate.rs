@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use crate::{OrderError, Rules, SafetyManual};
+
+/// A rule `before|after` broken by printing `after` first. Positions are
+/// zero-based indices into the update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub before: u32,
+    pub after: u32,
+    pub before_at: usize,
+    pub after_at: usize,
+}
+
+/// What checking one update against the rules found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Validation {
+    pub violations: Vec<Violation>,
+    /// The update sorted so every rule holds, if its rules allow one.
+    pub order: Result<Vec<u32>, OrderError>,
+}
+
+/// Every rule `update` breaks, ordered by where the late page is printed.
+pub fn violations(rules: &Rules, update: &[u32]) -> Vec<Violation> {
+    let positions: HashMap<u32, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(at, &page)| (page, at))
+        .collect();
+
+    let mut violations = Vec::new();
+    for (before_at, page) in update.iter().enumerate() {
+        for after in rules.get(page).into_iter().flatten() {
+            match positions.get(after) {
+                Some(&after_at) if after_at < before_at => violations.push(Violation {
+                    before: *page,
+                    after: *after,
+                    before_at,
+                    after_at,
+                }),
+                _ => {}
+            }
+        }
+    }
+    violations.sort_by_key(|violation| (violation.before_at, violation.after_at));
+    violations
+}
+
+/// Each update with the rules it breaks, one update per line and one line
+/// per broken rule, then why it cannot be sorted if it cannot. Positions
+/// count from 1.
+pub struct ValidationReport<'a> {
+    pub manual: &'a SafetyManual,
+    /// What [`SafetyManual::validate`] found for `manual`.
+    pub validation: &'a [Validation],
+    /// Leave out updates that break no rules.
+    pub rejected_only: bool,
+}
+
+impl Display for ValidationReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let updates = self.manual.updates().iter().zip(self.validation);
+        for (number, (update, validation)) in updates.enumerate() {
+            let violations = &validation.violations;
+            if self.rejected_only && violations.is_empty() && validation.order.is_ok() {
+                continue;
+            }
+
+            let pages: Vec<String> = update.iter().map(u32::to_string).collect();
+            write!(f, "update {} ({}): ", number + 1, pages.join(","))?;
+            match (violations.len(), &validation.order) {
+                (0, Ok(_)) => writeln!(f, "ok")?,
+                (0, Err(_)) => writeln!(f, "no rules broken")?,
+                (1, _) => writeln!(f, "rejected, 1 rule broken")?,
+                (n, _) => writeln!(f, "rejected, {n} rules broken")?,
+            }
+            for violation in violations {
+                writeln!(
+                    f,
+                    "  {}|{}: {} at position {} is printed after {} at position {}",
+                    violation.before,
+                    violation.after,
+                    violation.before,
+                    violation.before_at + 1,
+                    violation.after,
+                    violation.after_at + 1
+                )?;
+            }
+            if let Err(e) = &validation.order {
+                writeln!(f, "  cannot be sorted: {e}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use util::Solution;
+
+    use super::*;
+    use crate::PrintQueue;
+
+    #[test]
+    fn test_violations() {
+        let rules = Rules::from([(97, vec![75, 13]), (29, vec![13]), (75, vec![47])]);
+        assert_eq!(violations(&rules, &[75, 47, 61]), []);
+
+        assert_eq!(
+            violations(&rules, &[13, 75, 29, 97]),
+            [
+                Violation {
+                    before: 29,
+                    after: 13,
+                    before_at: 2,
+                    after_at: 0
+                },
+                Violation {
+                    before: 97,
+                    after: 13,
+                    before_at: 3,
+                    after_at: 0
+                },
+                Violation {
+                    before: 97,
+                    after: 75,
+                    before_at: 3,
+                    after_at: 1
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let manual = PrintQueue::parse("1|2\n2|3\n3|1\n\n3,2,1\n4,5,4\n6,7\n").unwrap();
+        let validation = manual.validate();
+        let report = ValidationReport {
+            manual: &manual,
+            validation: &validation,
+            rejected_only: true,
+        };
+        assert_eq!(
+            report.to_string(),
+            "update 1 (3,2,1): rejected, 2 rules broken
+  2|3: 2 at position 2 is printed after 3 at position 1
+  1|2: 1 at position 3 is printed after 2 at position 2
+  cannot be sorted: the rules for this update form a cycle: 1|2 2|3 3|1
+update 2 (4,5,4): no rules broken
+  cannot be sorted: page 4 appears more than once in the update
+"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use util::grid::Grid;
+use util::point::Point;
+use util::{ParseError, Solution};
+
+pub struct GuardGallivant;
+
+const DAY: u8 = GuardGallivant::DAY;
+
+impl Solution for GuardGallivant {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = Lab;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Lab::from_input(input)
+    }
+
+    fn part1(lab: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part_1(lab))
+    }
+
+    fn part2(lab: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part_2(lab))
+    }
+}
+
+/// The lab map together with where the guard starts.
+#[derive(Clone, Debug)]
+pub struct Lab {
+    map: Grid<char>,
+    guard: Point,
+}
+
+impl Lab {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let map: Grid<char> = input
+            .parse()
+            .map_err(|e| ParseError::from_grid(DAY, input, e))?;
+        let guard = map
+            .find(&'^')
+            .ok_or_else(|| ParseError::at_offset(DAY, input, 0, "the map has no guard (`^`)"))?;
+        Ok(Lab { map, guard })
+    }
+}
+
+pub fn part_1(lab: &Lab) -> usize {
+    simulate(&lab.map, lab.guard).visited_positions.len()
+}
+
+pub fn part_2(lab: &Lab) -> usize {
+    let mut obstructed_map = lab.map.clone();
+    lab.map
+        .find_all(&'.')
+        .filter(|&point| {
+            obstructed_map[point] = '#';
+            let result = simulate(&obstructed_map, lab.guard).is_looping;
+            obstructed_map[point] = '.';
+            result
+        })
+        .count()
+}
+
+struct SimulationResult {
+    visited_positions: HashSet<Point>,
+    is_looping: bool,
+}
+
+fn simulate(map: &Grid<char>, guard: Point) -> SimulationResult {
+    let directions = [Point::up(), Point::right(), Point::down(), Point::left()];
+    let mut direction = 0;
+    let mut position = guard;
+
+    let mut visited_positions = HashSet::new();
+    let mut seen_states = HashSet::with_capacity(map.width() * map.height());
+
+    loop {
+        let state = (position, direction);
+        if seen_states.contains(&state) {
+            return SimulationResult {
+                visited_positions,
+                is_looping: true,
+            };
+        }
+        seen_states.insert(state);
+
+        visited_positions.insert(position);
+
+        let next = position + directions[direction];
+        match map.get(next) {
+            None => break,
+            Some('#') => {
+                direction = (direction + 1) % 4;
+            }
+            Some(_) => {
+                position = next;
+            }
+        }
+    }
+
+    SimulationResult {
+        visited_positions,
+        is_looping: false,
+    }
+}
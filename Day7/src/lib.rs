@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use util::{ParseError, Solution};
+
+pub struct BridgeRepair;
+
+const DAY: u8 = BridgeRepair::DAY;
+
+impl Solution for BridgeRepair {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<(f64, Vec<f64>)>;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse(input)
+    }
+
+    fn part1(equations: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part1(equations))
+    }
+
+    fn part2(equations: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(part2(equations))
+    }
+}
+
+pub fn part1(equations: &[(f64, Vec<f64>)]) -> f64 {
+    equations
+        .iter()
+        .filter(|(v, nums)| check_part1(*v, nums.clone()))
+        .map(|eq| eq.0)
+        .sum()
+}
+
+pub fn part2(equations: &[(f64, Vec<f64>)]) -> f64 {
+    equations
+        .iter()
+        .filter(|(v, nums)| check_part2(*v, nums.clone()))
+        .map(|eq| eq.0)
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<(f64, Vec<f64>)>, ParseError> {
+    let number = |token: &str| match token.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        Ok(_) => Err(ParseError::at_token(
+            DAY,
+            input,
+            token,
+            format!("`{token}` is not a finite number"),
+        )),
+        Err(e) => Err(ParseError::at_token(
+            DAY,
+            input,
+            token,
+            format!("`{token}` is not a number: {e}"),
+        )),
+    };
+
+    input
+        .lines()
+        .map(|line| {
+            let (n, m) = line.split_once(':').ok_or_else(|| {
+                ParseError::at_token(DAY, input, line, "expected `test value: numbers`")
+            })?;
+            let value = number(n)?;
+            let nums: Vec<f64> = m.split_whitespace().map(number).collect::<Result<_, _>>()?;
+            if nums.is_empty() {
+                return Err(ParseError::at_token(
+                    DAY,
+                    input,
+                    m,
+                    "expected at least one number after `:`",
+                ));
+            }
+            Ok((value, nums))
+        })
+        .collect()
+}
+
+pub fn check_part1(target: f64, nums: Vec<f64>) -> bool {
+    if nums.len() == 1 {
+        return target == nums[0];
+    }
+    let n = nums.last().unwrap();
+    check_part1(target / n, nums.clone().drain(..(nums.len() - 1)).collect())
+        || check_part1(target - n, nums.clone().drain(..(nums.len() - 1)).collect())
+}
+
+pub fn check_part2(target: f64, nums: Vec<f64>) -> bool {
+    if nums.len() == 1 {
+        return target == nums[0];
+    }
+    let n = nums[0];
+    let m = nums[1];
+
+    let mut nm = vec![n * m];
+    nm.extend(nums.clone().drain(2..));
+
+    let mut na = vec![n + m];
+    na.extend(nums.clone().drain(2..));
+
+    let mut nc = vec![(n * 10f64.powi(m.log10() as i32 + 1) + m)];
+    nc.extend(nums.clone().drain(2..));
+
+    check_part2(target, nm) || check_part2(target, na) || check_part2(target, nc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "10: 2 5\n20: 4 5\n";
+        let expected = vec![(10.0, vec![2.0, 5.0]), (20.0, vec![4.0, 5.0])];
+        let result = parse(input);
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse("10: 2 5\n20: 4 x5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.snippet, "20: 4 x5");
+
+        let error = parse("10: 2 5\n10:\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.message, "expected at least one number after `:`");
+
+        for input in ["inf: 2", "10: 2 NaN", "10: -inf"] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_check_part1() {
+        assert!(check_part1(10.0, vec![2.0, 5.0]));
+        assert!(!check_part1(10.0, vec![2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_check_part2() {
+        assert!(check_part2(10.0, vec![2.0, 5.0]));
+        assert!(check_part2(20.0, vec![4.0, 5.0]));
+        assert!(!check_part2(10.0, vec![2.0, 3.0]));
+    }
+}
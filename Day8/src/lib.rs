@@ -1,52 +1,72 @@
 use std::{
     collections::{HashMap, HashSet},
+    fmt::Display,
     str::FromStr,
 };
-use util::point::Point;
+use util::{
+    grid::Grid,
+    point::Point,
+    ParseError, Solution,
+};
 
-#[derive(Debug)]
-struct AntennaMap {
-    antennae: HashMap<char, Vec<Point>>,
-    width: i32,
-    height: i32,
+pub struct ResonantCollinearity;
+
+const DAY: u8 = ResonantCollinearity::DAY;
+
+impl Solution for ResonantCollinearity {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = AntennaMap;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        AntennaMap::from_str(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(map.part_a())
+    }
+
+    fn part2(map: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(map.part_b())
+    }
 }
 
 #[derive(Debug)]
-enum AntennaMapErr {}
+pub struct AntennaMap {
+    antennae: HashMap<char, Vec<Point>>,
+    grid: Grid<char>,
+}
 
 impl FromStr for AntennaMap {
-    type Err = AntennaMapErr;
+    type Err = ParseError;
 
     fn from_str(puzzle: &str) -> Result<Self, Self::Err> {
-        let height = puzzle.lines().count() as i32;
-        let width = puzzle.lines().next().unwrap_or("").len() as i32;
+        let grid: Grid<char> = puzzle
+            .parse()
+            .map_err(|e| ParseError::from_grid(DAY, puzzle, e))?;
 
         let mut antennae = HashMap::new();
-        for (row_idx, row) in puzzle.lines().enumerate() {
-            for (col_idx, cell) in row.chars().enumerate() {
-                if cell != '.' {
-                    antennae
-                        .entry(cell)
-                        .or_insert_with(Vec::new)
-                        .push(Point(row_idx as i32, col_idx as i32));
-                }
+        for (point, &cell) in grid.iter() {
+            if cell != '.' {
+                antennae
+                    .entry(cell)
+                    .or_insert_with(Vec::new)
+                    .push(point);
             }
         }
 
-        Ok(AntennaMap {
-            antennae,
-            width,
-            height,
-        })
+        Ok(AntennaMap { antennae, grid })
     }
 }
 
 impl AntennaMap {
     fn in_bounds(&self, point: &Point) -> bool {
-        point.0 >= 0 && point.1 >= 0 && point.0 < self.height && point.1 < self.width
+        self.grid.contains(*point)
     }
 
-    fn part_a(&self) -> usize {
+    pub fn part_a(&self) -> usize {
         let mut antinodes = HashSet::new();
 
         for antenna_group in self.antennae.values() {
@@ -54,12 +74,12 @@ impl AntennaMap {
                 for right in &antenna_group[i + 1..] {
                     let diff = left.diff(right);
 
-                    let node_0 = left.sub(&diff);
+                    let node_0 = *left - diff;
                     if self.in_bounds(&node_0) {
                         antinodes.insert(node_0);
                     }
 
-                    let node_1 = right.add(&diff);
+                    let node_1 = *right + diff;
                     if self.in_bounds(&node_1) {
                         antinodes.insert(node_1);
                     }
@@ -70,7 +90,7 @@ impl AntennaMap {
         antinodes.len()
     }
 
-    fn part_b(&self) -> usize {
+    pub fn part_b(&self) -> usize {
         let mut antinodes = HashSet::new();
 
         for antenna_group in self.antennae.values() {
@@ -81,13 +101,13 @@ impl AntennaMap {
                     let mut node = *left;
                     while self.in_bounds(&node) {
                         antinodes.insert(node);
-                        node = node.sub(&diff);
+                        node -= diff;
                     }
 
                     node = *right;
                     while self.in_bounds(&node) {
                         antinodes.insert(node);
-                        node = node.add(&diff);
+                        node += diff;
                     }
                 }
             }
@@ -97,14 +117,6 @@ impl AntennaMap {
     }
 }
 
-fn main() {
-    let puzzle = include_str!("../input.txt");
-    let map = AntennaMap::from_str(puzzle).expect("Failed to parse the antenna map.");
-
-    println!("Part A: {}", map.part_a());
-    println!("Part B: {}", map.part_b());
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,26 +1,67 @@
-use std::{collections::HashSet, num::ParseIntError, str::FromStr};
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use util::{ParseError, Solution};
+
+pub struct DiskFragmenter;
+
+const DAY: u8 = DiskFragmenter::DAY;
+
+impl Solution for DiskFragmenter {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = DiskDefrag;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        DiskDefrag::from_str(input)
+    }
+
+    fn part1(defrag: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(defrag.part_a())
+    }
+
+    fn part2(defrag: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(defrag.part_b())
+    }
+}
 
 #[derive(Debug)]
-struct DiskDefrag {
+pub struct DiskDefrag {
     pairs: Vec<(i32, i32)>,
 }
 
 impl FromStr for DiskDefrag {
-    type Err = ParseIntError;
+    type Err = ParseError;
     fn from_str(puzzle: &str) -> Result<Self, Self::Err> {
-        let mut pairs = vec![];
-        let trimmed = puzzle.trim().chars().collect::<Vec<char>>();
-        for idx in (0..trimmed.len()).step_by(2) {
-            let occupied = &trimmed[idx].to_string().parse()?;
-            let free = &trimmed.get(idx + 1).unwrap_or(&'0').to_string().parse()?;
-            pairs.push((*occupied, *free));
+        let trimmed = puzzle.trim();
+        if trimmed.is_empty() {
+            return Err(ParseError::at_offset(
+                DAY,
+                puzzle,
+                puzzle.len(),
+                "expected a disk map, found no digits",
+            ));
         }
+        let digits = trimmed
+            .char_indices()
+            .map(|(idx, c)| {
+                c.to_digit(10).map(|digit| digit as i32).ok_or_else(|| {
+                    let message = format!("expected a digit, found `{c}`");
+                    ParseError::at_token(DAY, puzzle, &trimmed[idx..], message)
+                })
+            })
+            .collect::<Result<Vec<i32>, _>>()?;
+        let pairs = digits
+            .chunks(2)
+            .map(|pair| (pair[0], pair.get(1).copied().unwrap_or(0)))
+            .collect();
         Ok(DiskDefrag { pairs })
     }
 }
 
 impl DiskDefrag {
-    fn part_a(&self) -> usize {
+    pub fn part_a(&self) -> usize {
         let mut pairs = self.pairs.clone();
         let mut unaccounted: i32 = pairs.iter().map(|(x, _)| x).sum();
 
@@ -66,7 +107,7 @@ impl DiskDefrag {
         acc
     }
 
-    fn part_b(&self) -> usize {
+    pub fn part_b(&self) -> usize {
         let mut unaccounted: i32 = self.pairs.iter().map(|(x, _)| x).sum();
         let mut acc = 0;
         let mut start_idx = 0;
@@ -134,15 +175,6 @@ impl DiskDefrag {
     }
 }
 
-fn main() {
-    let puzzle = include_str!("../input.txt");
-    let defrag = DiskDefrag::from_str(puzzle).expect("Unable to parse input");
-    println!("Part A: {}", defrag.part_a());
-    println!("Part B: {}", defrag.part_b());
-}
-
-
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,6 +213,15 @@ mod tests {
         assert_eq!(2858, result);
     }
 
+    #[test]
+    fn test_parse_error() {
+        for puzzle in ["", "\n", "  \r\n"] {
+            let error = DiskDefrag::from_str(puzzle).unwrap_err();
+            assert_eq!(error.message, "expected a disk map, found no digits");
+        }
+        assert!(DiskDefrag::from_str("12x4").is_err());
+    }
+
     #[test]
     fn test_acc() {
         let input = "00992111777.44.333....5555.6666.....8888..";
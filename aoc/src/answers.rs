@@ -0,0 +1,182 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use miette::{IntoDiagnostic, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::{Outcome, Part};
+
+/// File name of the recorded answers, kept next to the puzzle inputs they
+/// belong to.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Answers already accepted for each day, keyed as `dayNN`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Answers(BTreeMap<String, Recorded>);
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Recorded {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl Recorded {
+    fn part(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+impl Answers {
+    /// Reads the answers file, treating a missing file as no answers yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(e)
+                    .into_diagnostic()
+                    .wrap_err(path.display().to_string())
+            }
+        };
+
+        toml::from_str(&text)
+            .into_diagnostic()
+            .wrap_err(path.display().to_string())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self).into_diagnostic()?;
+        fs::write(path, text)
+            .into_diagnostic()
+            .wrap_err(path.display().to_string())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        let recorded = self.0.get(&key(day))?;
+        match part {
+            Part::One => recorded.part1.as_deref(),
+            Part::Two => recorded.part2.as_deref(),
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: String) {
+        *self.0.entry(key(day)).or_default().part(part) = Some(answer);
+    }
+
+    /// Compares the answer `outcome` produced for `part` with the recorded
+    /// one.
+    pub fn verify(&self, outcome: &Outcome, part: Part) -> Verdict {
+        if let Err(e) = &outcome.parse {
+            return Verdict::Error(e.to_string());
+        }
+
+        let Some(answer) = outcome.answer(part) else {
+            return Verdict::Error("no answer".to_string());
+        };
+
+        let actual = match &answer.value {
+            Ok(actual) => actual,
+            Err(e) => return Verdict::Error(e.to_string()),
+        };
+        match self.get(outcome.day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.clone(),
+            },
+        }
+    }
+}
+
+fn key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+    Error(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL: expected {expected}, got {actual}")
+            }
+            Verdict::Missing => write!(f, "not recorded"),
+            Verdict::Error(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Answer;
+
+    fn outcome(part1: miette::Result<&str>) -> Outcome {
+        Outcome {
+            day: 3,
+            title: "Mull It Over",
+            parse: Ok(Duration::ZERO),
+            part1: Some(Answer {
+                value: part1.map(str::to_string),
+                elapsed: Duration::ZERO,
+            }),
+            part2: None,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(3, Part::One, "161".to_string());
+        let text = toml::to_string(&answers).unwrap();
+        assert_eq!(text, "[day03]\npart1 = \"161\"\n");
+
+        let answers: Answers = toml::from_str(&text).unwrap();
+        assert_eq!(answers.get(3, Part::One), Some("161"));
+        assert_eq!(answers.get(3, Part::Two), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut answers = Answers::default();
+        assert_eq!(
+            answers.verify(&outcome(Ok("161")), Part::One),
+            Verdict::Missing
+        );
+
+        answers.record(3, Part::One, "161".to_string());
+        assert_eq!(
+            answers.verify(&outcome(Ok("161")), Part::One),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verify(&outcome(Err(miette::miette!("no answer here"))), Part::One),
+            Verdict::Error("no answer here".to_string())
+        );
+        assert_eq!(
+            answers.verify(&outcome(Ok("160")), Part::One),
+            Verdict::Fail {
+                expected: "161".to_string(),
+                actual: "160".to_string()
+            }
+        );
+    }
+}
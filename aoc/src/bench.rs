@@ -0,0 +1,132 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use miette::miette;
+use serde::Serialize;
+use util::Solution;
+
+/// Timings of one stage over every iteration, in nanoseconds so the report
+/// can be compared between commits without parsing units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        samples.sort_unstable();
+
+        let nanos = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+
+        Stats {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(median),
+            mean_ns: nanos(mean),
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns)
+    }
+}
+
+/// Parse and solve timings for one day.
+#[derive(Debug, Serialize)]
+pub struct Bench {
+    pub day: u8,
+    pub title: &'static str,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Times `iterations` runs of parsing and of each part. The parts all work
+/// on the same parsed input, so parse time is not counted twice. A part
+/// that fails is reported instead of timed.
+pub fn run<S: Solution>(input: &str, iterations: u32) -> miette::Result<Bench> {
+    let iterations = iterations.max(1);
+    let parsed = S::parse(input).map_err(|e| miette!("{e}"))?;
+    S::part1(&parsed).map_err(|e| miette!("part 1: {e}"))?;
+    S::part2(&parsed).map_err(|e| miette!("part 2: {e}"))?;
+
+    let parse = measure(iterations, || {
+        black_box(S::parse(black_box(input)).is_ok());
+    });
+    let part1 = measure(iterations, || {
+        black_box(
+            S::part1(black_box(&parsed))
+                .map(|answer| answer.to_string())
+                .is_ok(),
+        );
+    });
+    let part2 = measure(iterations, || {
+        black_box(
+            S::part2(black_box(&parsed))
+                .map(|answer| answer.to_string())
+                .is_ok(),
+        );
+    });
+
+    Ok(Bench {
+        day: S::DAY,
+        title: S::TITLE,
+        iterations,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn measure(iterations: u32, mut stage: impl FnMut()) -> Stats {
+    let mut samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            stage();
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&mut samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples = [5, 1, 3, 7].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::from_samples(&mut samples),
+            Stats {
+                min_ns: 1,
+                median_ns: 4,
+                mean_ns: 4,
+            }
+        );
+
+        let mut samples = [9, 2, 4].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::from_samples(&mut samples).median(),
+            Duration::from_nanos(4)
+        );
+    }
+}
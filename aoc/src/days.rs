@@ -0,0 +1,16 @@
+use crate::Day;
+
+pub static DAYS: &[&dyn Day] = &[
+    &day1::HistorianHysteria,
+    &day2::RedNosedReports,
+    &day3::MullItOver,
+    &day4::CeresSearch,
+    &day5::PrintQueue,
+    &day6::GuardGallivant,
+    &day7::BridgeRepair,
+    &day8::ResonantCollinearity,
+    &day9::DiskFragmenter,
+    &day10::HoofIt,
+    &day11::PlutonianPebbles,
+    &day12::GardenGroups,
+];
@@ -0,0 +1,81 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use miette::{IntoDiagnostic, Result, WrapErr};
+use serde::Deserialize;
+
+use crate::{Day, Part};
+
+/// Where the `dayNN.toml` example files live in the repository.
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../examples");
+
+/// A puzzle example with the answers the puzzle text gives for it. Parts
+/// the example says nothing about are left out.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ExampleFile {
+    #[serde(default, rename = "example")]
+    examples: Vec<Example>,
+}
+
+/// Reads `dayNN.toml` from `dir`. A day without a file has no examples.
+pub fn load(dir: &Path, day: u8) -> Result<Vec<Example>> {
+    let path = dir.join(format!("day{day:02}.toml"));
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(e)
+                .into_diagnostic()
+                .wrap_err(path.display().to_string())
+        }
+    };
+
+    let file: ExampleFile = toml::from_str(&text)
+        .into_diagnostic()
+        .wrap_err(path.display().to_string())?;
+    Ok(file.examples)
+}
+
+/// Runs `part` of `day` on the example and compares it with the recorded
+/// answer.
+pub fn check(day: &dyn Day, example: &Example, part: Part) -> Result<(), String> {
+    let Some(expected) = example.expected(part) else {
+        return Ok(());
+    };
+
+    let outcome = day.run(&example.input, Some(part));
+    if let Err(e) = outcome.parse {
+        return Err(format!("parsing example `{}` failed: {e}", example.name));
+    }
+
+    match outcome.answer(part).map(|answer| &answer.value) {
+        Some(Ok(value)) if value == expected => Ok(()),
+        Some(Ok(value)) => Err(format!(
+            "example `{}` part {part}: expected {expected}, got {value}",
+            example.name
+        )),
+        Some(Err(e)) => Err(format!(
+            "example `{}` part {part} failed: {e}",
+            example.name
+        )),
+        None => Err(format!("example `{}` part {part}: no answer", example.name)),
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use miette::miette;
+use util::Solution;
+
+pub mod answers;
+pub mod bench;
+mod days;
+pub mod examples;
+
+pub use days::DAYS;
+
+/// Object-safe view of a [`Solution`], so the runner can hold every day in
+/// one list.
+pub trait Day: Sync {
+    fn number(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn run(&self, input: &str, part: Option<Part>) -> Outcome;
+    fn bench(&self, input: &str, iterations: u32) -> miette::Result<bench::Bench>;
+}
+
+impl<S: Solution + Sync> Day for S {
+    fn number(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn run(&self, input: &str, part: Option<Part>) -> Outcome {
+        run::<S>(input, part)
+    }
+
+    fn bench(&self, input: &str, iterations: u32) -> miette::Result<bench::Bench> {
+        bench::run::<S>(input, iterations)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    fn wanted(self, selected: Option<Part>) -> bool {
+        selected.is_none_or(|part| part == self)
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, got {value}")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub fn find(number: u8) -> Option<&'static dyn Day> {
+    DAYS.iter().copied().find(|day| day.number() == number)
+}
+
+/// An answer, or why the part has none, together with the time spent
+/// producing it.
+pub struct Answer {
+    pub value: miette::Result<String>,
+    pub elapsed: Duration,
+}
+
+/// The result of running one day: how long parsing took (or why it failed)
+/// and the answers for the requested parts.
+pub struct Outcome {
+    pub day: u8,
+    pub title: &'static str,
+    pub parse: miette::Result<Duration>,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Outcome {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        let parse = self.parse.as_ref().copied().unwrap_or_default();
+        [&self.part1, &self.part2]
+            .into_iter()
+            .flatten()
+            .fold(parse, |total, answer| total + answer.elapsed)
+    }
+}
+
+pub fn run<S: Solution>(input: &str, part: Option<Part>) -> Outcome {
+    let mut outcome = Outcome {
+        day: S::DAY,
+        title: S::TITLE,
+        parse: Ok(Duration::ZERO),
+        part1: None,
+        part2: None,
+    };
+
+    let start = Instant::now();
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            outcome.parse = Err(miette!("{e}"));
+            return outcome;
+        }
+    };
+    outcome.parse = Ok(start.elapsed());
+
+    let timed = |solve: &dyn Fn() -> miette::Result<String>| {
+        let start = Instant::now();
+        let value = solve();
+        Answer {
+            value,
+            elapsed: start.elapsed(),
+        }
+    };
+    if Part::One.wanted(part) {
+        outcome.part1 = Some(timed(&|| answer(S::part1(&parsed))));
+    }
+    if Part::Two.wanted(part) {
+        outcome.part2 = Some(timed(&|| answer(S::part2(&parsed))));
+    }
+
+    outcome
+}
+
+fn answer(result: Result<impl fmt::Display, impl fmt::Display>) -> miette::Result<String> {
+    result
+        .map(|value| value.to_string())
+        .map_err(|e| miette!("{e}"))
+}
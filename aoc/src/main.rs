@@ -0,0 +1,744 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, IsTerminal, Read};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use aoc::answers::{Answers, Verdict, ANSWERS_FILE};
+use aoc::bench::{Bench, Stats};
+use aoc::{Answer, Day, Outcome, Part, DAYS};
+use clap::{Parser, Subcommand, ValueEnum};
+use day1::metrics::{self, Value};
+use day1::stream::{self, StreamConfig};
+use day1::{Delimiter, Metric, Number, Schema};
+use day2::{Policy, Report};
+use day3::instruction::{CONDITIONAL, MULTIPLY};
+use day3::interpreter::{self, Program};
+use day3::total::{Arithmetic, Total};
+use day4::render::render;
+use day4::{Ragged, Stencil, Style, WordSearch, Wrap};
+use day5::{PrintQueue, ValidationReport};
+use miette::{miette, IntoDiagnostic, Result, WrapErr};
+use util::grid::Grid;
+use util::input::{self, InputError, Inputs};
+use util::Solution;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    /// Directory holding the `dayNN.txt` puzzle inputs [default: $AOC_INPUT_DIR, or `inputs`].
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day in sequence when no day is given.
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input to use instead of the cached one, or `-` to read stdin.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Save the accepted answer for a part, computing it when not given.
+    Record {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The accepted answer; defaults to what the solution prints now.
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Rerun days and compare their answers with the recorded ones.
+    Verify {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Time parsing and both parts over repeated runs. Build with `--release`
+    /// for meaningful numbers.
+    Bench {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        #[arg(long, default_value_t = 10)]
+        iterations: u32,
+        /// Also write the timings as JSON, e.g. to `bench_output.txt`.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Day 1 over location lists too large for memory, streamed through
+    /// sorted runs spilled to disk.
+    Lists {
+        /// Lists to read instead of the cached day 1 input, or `-` for stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// IDs per column sorted in memory before a run is spilled.
+        #[arg(long, default_value_t = StreamConfig::default().run_len)]
+        run_len: usize,
+        /// Directory for spilled runs [default: the system temporary directory].
+        #[arg(long)]
+        spill_dir: Option<PathBuf>,
+        /// How many spilled runs are merged at once, and so how many files
+        /// each column keeps open.
+        #[arg(long, default_value_t = StreamConfig::default().fan_in)]
+        fan_in: usize,
+    },
+    /// Day 1's distance and similarity between two columns of a table.
+    Compare {
+        /// Table to read instead of the cached day 1 input, or `-` for stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// `whitespace`, `csv`, `tsv` or any single character.
+        #[arg(long, default_value_t = Delimiter::Whitespace)]
+        delimiter: Delimiter,
+        /// How many columns every row must have.
+        #[arg(long)]
+        columns: Option<usize>,
+        /// Column holding the left list, counting from 1.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        left: u16,
+        /// Column holding the right list, counting from 1.
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u16).range(1..))]
+        right: u16,
+        /// Skip the first line.
+        #[arg(long)]
+        header: bool,
+        /// Number type to read and add up the IDs in.
+        #[arg(long, value_enum, default_value_t = Width::U32)]
+        width: Width,
+        /// Metrics to report, comma-separated: distance, similarity, jaccard,
+        /// overlap, kendall-tau, spearman, edit-distance or contributions.
+        #[arg(long = "metric", value_delimiter = ',', default_values = ["distance", "similarity"])]
+        metrics: Vec<Metric>,
+        /// How many pairs and IDs the contributions report lists.
+        #[arg(long, default_value_t = metrics::DEFAULT_TOP)]
+        top: usize,
+    },
+    /// Day 2's reports annotated with why each one is unsafe and which
+    /// level, if any, the dampener would remove.
+    Reports {
+        /// Reports to read instead of the cached day 2 input, or `-` for stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Only list the unsafe reports.
+        #[arg(long = "unsafe")]
+        unsafe_only: bool,
+        /// What to do with empty or non-numeric lines: `reject`, `skip`, or
+        /// `unsafe` to list and count them as unsafe reports.
+        #[arg(long, default_value_t = Policy::Reject)]
+        invalid: Policy,
+        /// How much adjacent levels may differ, as MIN..=MAX.
+        #[arg(long, value_parser = day2::dampener::parse_steps, default_value = "1..=3")]
+        steps: RangeInclusive<u32>,
+        /// How many bad levels the dampener may remove from one report.
+        #[arg(long, default_value_t = day2::Dampener::default().max_removals)]
+        max_removals: usize,
+    },
+    /// Day 3 over a memory dump of any size, read a chunk at a time.
+    Memory {
+        /// Dump to read instead of the cached day 3 input, or `-` for stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Bytes read per chunk.
+        #[arg(long, default_value_t = interpreter::CHUNK_LEN)]
+        chunk_len: usize,
+        /// Number type to add the products up in.
+        #[arg(long, value_enum, default_value_t = TotalWidth::I64)]
+        total: TotalWidth,
+        /// Let the total wrap around instead of stopping at the instruction
+        /// that overflows it.
+        #[arg(long)]
+        wrapping: bool,
+    },
+    /// Day 4's letter grid with the words or shapes found in it highlighted.
+    Highlight {
+        /// Grid to read instead of the cached day 4 input, or `-` for stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Words to find, comma-separated [default: XMAS, unless a stencil
+        /// is given].
+        #[arg(long = "word", value_delimiter = ',')]
+        words: Vec<String>,
+        /// A shape such as `M.S/.A./M.S` to find in every orientation, with
+        /// `.` matching any letter.
+        #[arg(long)]
+        stencil: Option<Stencil>,
+        /// Let words run off one edge and continue from the opposite one.
+        #[arg(long)]
+        wrap: bool,
+        /// `ansi`, `plain` or `html` [default: ansi on a terminal, otherwise
+        /// plain].
+        #[arg(long)]
+        style: Option<Style>,
+        /// What to do with rows of different widths: `reject`, or `pad` to
+        /// fill them out with `.` (`pad:<character>` for another filler).
+        #[arg(long, default_value_t = Ragged::Reject)]
+        ragged: Ragged,
+    },
+    /// Day 5's updates with every page-ordering rule each one breaks.
+    Updates {
+        /// Manual to read instead of the cached day 5 input, or `-` for stdin.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Only list the updates that break a rule.
+        #[arg(long)]
+        rejected: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TotalWidth {
+    I32,
+    I64,
+    I128,
+    Big,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Width {
+    U32,
+    U64,
+    I64,
+    Big,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let inputs = cli.input_dir.map_or_else(Inputs::from_env, Inputs::new);
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let part = part
+                .map(Part::try_from)
+                .transpose()
+                .map_err(|e| miette!(e))?;
+            match day {
+                Some(number) => {
+                    let day = find_day(number)?;
+                    let input = load_input(&inputs, day, input.as_deref()).into_diagnostic()?;
+                    let outcome = day.run(&input, part);
+                    print_results(vec![Row::from(&outcome)]);
+                    outcome.parse?;
+                    for answer in [outcome.part1, outcome.part2].into_iter().flatten() {
+                        answer.value?;
+                    }
+                }
+                None => {
+                    let rows: Vec<Row> = DAYS
+                        .iter()
+                        .map(|&day| match inputs.load(day.number()) {
+                            Ok(input) => Row::from(&day.run(&input, part)),
+                            Err(e) => Row::failed(day, &e),
+                        })
+                        .collect();
+                    print_results(rows);
+                }
+            }
+        }
+        Command::Record { day, part, answer } => {
+            let day = find_day(day)?;
+            let part = Part::try_from(part).map_err(|e| miette!(e))?;
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let input = inputs.load(day.number()).into_diagnostic()?;
+                    let outcome = day.run(&input, Some(part));
+                    outcome.parse.as_ref().map_err(|e| miette!("{e}"))?;
+                    match outcome.answer(part).map(|answer| &answer.value) {
+                        Some(Ok(value)) => value.clone(),
+                        Some(Err(e)) => {
+                            return Err(miette!("day {} part {part} failed: {e}", day.number()))
+                        }
+                        None => {
+                            return Err(miette!("day {} part {part} gave no answer", day.number()))
+                        }
+                    }
+                }
+            };
+
+            let path = inputs.dir().join(ANSWERS_FILE);
+            let mut answers = Answers::load(&path)?;
+            println!("day {} part {part}: recorded {answer}", day.number());
+            answers.record(day.number(), part, answer);
+            answers.save(&path)?;
+        }
+        Command::Verify { day } => {
+            let days = match day {
+                Some(number) => vec![find_day(number)?],
+                None => DAYS.to_vec(),
+            };
+            let answers = Answers::load(&inputs.dir().join(ANSWERS_FILE))?;
+
+            let mut failures = 0;
+            let rows: Vec<Row> = days
+                .into_iter()
+                .map(|day| match inputs.load(day.number()) {
+                    Ok(input) => {
+                        let outcome = day.run(&input, None);
+                        let verdicts =
+                            [Part::One, Part::Two].map(|part| answers.verify(&outcome, part));
+                        failures += verdicts
+                            .iter()
+                            .filter(|verdict| {
+                                matches!(verdict, Verdict::Fail { .. } | Verdict::Error(_))
+                            })
+                            .count();
+                        Row::verified(&outcome, verdicts)
+                    }
+                    Err(e) => Row::failed(day, &e),
+                })
+                .collect();
+            print_results(rows);
+
+            if failures > 0 {
+                return Err(miette!("{failures} answer(s) did not verify"));
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            output,
+        } => {
+            let days = match day {
+                Some(number) => vec![find_day(number)?],
+                None => DAYS.to_vec(),
+            };
+
+            let mut benches = Vec::new();
+            let mut rows = Vec::new();
+            for day in days {
+                let result = inputs
+                    .load(day.number())
+                    .map_err(|e| match e {
+                        InputError::Missing { path, .. } => format!("missing {}", path.display()),
+                        e => format!("error: {e}"),
+                    })
+                    .and_then(|input| {
+                        day.bench(&input, iterations)
+                            .map_err(|e| format!("error: {e}"))
+                    });
+                match result {
+                    Ok(bench) => {
+                        rows.extend(bench_rows(&bench));
+                        benches.push(bench);
+                    }
+                    Err(e) => rows.push([
+                        day.number().to_string(),
+                        day.title().to_string(),
+                        e,
+                        "-".to_string(),
+                        "-".to_string(),
+                        "-".to_string(),
+                    ]),
+                }
+            }
+            print_table(
+                ["Day", "Title", "Stage", "Min", "Median", "Mean"],
+                [true, false, false, true, true, true],
+                &rows,
+            );
+
+            if let Some(path) = output {
+                let report = serde_json::to_string_pretty(&benches).into_diagnostic()?;
+                fs::write(&path, report + "\n").into_diagnostic()?;
+            }
+        }
+        Command::Lists {
+            input,
+            run_len,
+            spill_dir,
+            fan_in,
+        } => {
+            let config = StreamConfig {
+                run_len,
+                spill_dir,
+                fan_in,
+            };
+            let path = input.unwrap_or_else(|| inputs.path(1));
+            let totals = if path == Path::new("-") {
+                stream::totals(io::stdin().lock(), &config)
+            } else {
+                let file = File::open(&path)
+                    .into_diagnostic()
+                    .wrap_err_with(|| path.display().to_string())?;
+                stream::totals(BufReader::new(file), &config)
+            }
+            .into_diagnostic()?;
+
+            println!("pairs:      {}", totals.pairs);
+            println!("distance:   {}", totals.distance);
+            println!("similarity: {}", totals.similarity);
+        }
+        Command::Compare {
+            input,
+            delimiter,
+            columns,
+            left,
+            right,
+            header,
+            width,
+            metrics,
+            top,
+        } => {
+            let input = load_input(&inputs, find_day(1)?, input.as_deref()).into_diagnostic()?;
+            let schema = Schema {
+                delimiter,
+                columns,
+                pair: (usize::from(left) - 1, usize::from(right) - 1),
+                header,
+            };
+            let metrics: Vec<Metric> = metrics
+                .into_iter()
+                .map(|metric| match metric {
+                    Metric::Contributions { .. } => Metric::Contributions { top },
+                    metric => metric,
+                })
+                .collect();
+            match width {
+                Width::U32 => compare::<u32>(&input, &schema, &metrics)?,
+                Width::U64 => compare::<u64>(&input, &schema, &metrics)?,
+                Width::I64 => compare::<i64>(&input, &schema, &metrics)?,
+                Width::Big => compare::<num_bigint::BigInt>(&input, &schema, &metrics)?,
+            }
+        }
+        Command::Reports {
+            input,
+            unsafe_only,
+            invalid,
+            steps,
+            max_removals,
+        } => {
+            let input = load_input(&inputs, find_day(2)?, input.as_deref()).into_diagnostic()?;
+            let reports = day2::report::parse_with(&input, invalid).into_diagnostic()?;
+
+            let dampener = day2::Dampener {
+                steps,
+                max_removals,
+            };
+            let (mut safe, mut fixable) = (0, 0);
+            for report in &reports {
+                let annotated = match report {
+                    Report::Levels { levels, .. } => {
+                        let explanation = day2::explain(levels, &dampener);
+                        if explanation.is_safe() {
+                            safe += 1;
+                            if unsafe_only {
+                                continue;
+                            }
+                        } else if explanation.fix.is_some() {
+                            fixable += 1;
+                        }
+                        day2::explain::annotate(levels, &explanation)
+                    }
+                    Report::Invalid(e) => format!("invalid: {}", e.message),
+                };
+                println!("{:>5}  {annotated}", report.line());
+            }
+            let fixed = match dampener.max_removals {
+                1 => "one removal".to_string(),
+                k => format!("up to {k} removals"),
+            };
+            println!(
+                "{safe} safe, {fixable} safe with {fixed}, {} unsafe",
+                reports.len() - safe - fixable
+            );
+        }
+        Command::Highlight {
+            input,
+            words,
+            stencil,
+            wrap,
+            style,
+            ragged,
+        } => {
+            let input = load_input(&inputs, find_day(4)?, input.as_deref()).into_diagnostic()?;
+            let grid = day4::loader::load(&input, ragged).into_diagnostic()?;
+            let wrap = if wrap { Wrap::Around } else { Wrap::No };
+            let style = style.unwrap_or(if io::stdout().is_terminal() {
+                Style::Ansi
+            } else {
+                Style::Plain
+            });
+
+            let (rendered, count) = highlight(&grid, words, stencil, wrap, style);
+            print!("{rendered}");
+            eprintln!("{} x {} grid, {count} matches", grid.width(), grid.height());
+        }
+        Command::Updates { input, rejected } => {
+            let input = load_input(&inputs, find_day(5)?, input.as_deref()).into_diagnostic()?;
+            let manual = PrintQueue::parse(&input).into_diagnostic()?;
+            let validation = manual.validate();
+            print!(
+                "{}",
+                ValidationReport {
+                    manual: &manual,
+                    validation: &validation,
+                    rejected_only: rejected,
+                }
+            );
+
+            let broken = validation
+                .iter()
+                .filter(|v| !v.violations.is_empty())
+                .count();
+            let unsorted = validation.iter().filter(|v| v.order.is_err()).count();
+            println!(
+                "{} updates, {} in order, {broken} rejected, {unsorted} cannot be sorted",
+                validation.len(),
+                validation.len() - broken
+            );
+        }
+        Command::Memory {
+            input,
+            chunk_len,
+            total,
+            wrapping,
+        } => {
+            let path = input.unwrap_or_else(|| inputs.path(3));
+            let reader: Box<dyn Read> = if path == Path::new("-") {
+                Box::new(io::stdin().lock())
+            } else {
+                let file = File::open(&path)
+                    .into_diagnostic()
+                    .wrap_err_with(|| path.display().to_string())?;
+                Box::new(file)
+            };
+            let arithmetic = if wrapping {
+                Arithmetic::Wrapping
+            } else {
+                Arithmetic::Checked
+            };
+            match total {
+                TotalWidth::I32 => memory::<i32>(reader, chunk_len, arithmetic)?,
+                TotalWidth::I64 => memory::<i64>(reader, chunk_len, arithmetic)?,
+                TotalWidth::I128 => memory::<i128>(reader, chunk_len, arithmetic)?,
+                TotalWidth::Big => memory::<num_bigint::BigInt>(reader, chunk_len, arithmetic)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn highlight(
+    grid: &Grid<char>,
+    words: Vec<String>,
+    stencil: Option<Stencil>,
+    wrap: Wrap,
+    style: Style,
+) -> (String, usize) {
+    let words = if words.is_empty() && stencil.is_none() {
+        vec!["XMAS".to_string()]
+    } else {
+        words
+    };
+
+    let mut marked = Vec::new();
+    let mut count = 0;
+    if !words.is_empty() {
+        let search = WordSearch::new(&words);
+        let matches = search.find(grid, wrap);
+        count += matches.len();
+        for found in &matches {
+            marked.extend(search.cells(found, grid, wrap));
+        }
+    }
+    if let Some(stencil) = &stencil {
+        let matches = stencil.find(grid);
+        count += matches.len();
+        for found in &matches {
+            marked.extend(stencil.cells(found));
+        }
+    }
+
+    (render(grid, marked, style), count)
+}
+
+fn memory<T: Total>(reader: impl Read, chunk_len: usize, arithmetic: Arithmetic) -> Result<()> {
+    let mut programs = [
+        Program::<T>::new(MULTIPLY, arithmetic),
+        Program::new(CONDITIONAL, arithmetic),
+    ];
+    interpreter::stream(reader, &mut programs, chunk_len).into_diagnostic()?;
+
+    let [part1, part2] = programs.map(|mut program| program.finish());
+    println!("part 1: {}", part1.into_diagnostic()?);
+    println!("part 2: {}", part2.into_diagnostic()?);
+    Ok(())
+}
+
+fn compare<N: Number>(input: &str, schema: &Schema, metrics: &[Metric]) -> Result<()> {
+    let (left, right) = schema.read::<N>(input).into_diagnostic()?;
+    let width = metrics
+        .iter()
+        .map(|metric| metric.name().len())
+        .chain(["pairs".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("{:width$}  {}", "pairs", left.len());
+    for metric in metrics {
+        let value = metric.compute(&left, &right).into_diagnostic()?;
+        match value {
+            Value::Contributions(report) => println!("{}:\n{report}", metric.name()),
+            value => println!("{:width$}  {value}", metric.name()),
+        }
+    }
+    Ok(())
+}
+
+fn find_day(number: u8) -> Result<&'static dyn Day> {
+    aoc::find(number).ok_or_else(|| miette!("day {number} has no solution yet"))
+}
+
+fn load_input(inputs: &Inputs, day: &dyn Day, path: Option<&Path>) -> Result<String, InputError> {
+    match path {
+        Some(path) if path == Path::new("-") => input::read_stdin(),
+        Some(path) => input::read_file(path),
+        None => inputs.load(day.number()),
+    }
+}
+
+struct Row {
+    day: String,
+    title: String,
+    part1: String,
+    part2: String,
+    time: String,
+}
+
+impl Row {
+    fn failed(day: &dyn Day, error: &InputError) -> Self {
+        let error = match error {
+            InputError::Missing { path, .. } => format!("missing {}", path.display()),
+            _ => format!("error: {error}"),
+        };
+        Row {
+            day: day.number().to_string(),
+            title: day.title().to_string(),
+            part1: error.clone(),
+            part2: error,
+            time: "-".to_string(),
+        }
+    }
+
+    fn verified(outcome: &Outcome, [part1, part2]: [Verdict; 2]) -> Self {
+        let time = match outcome.parse {
+            Ok(_) => format!("{:.2?}", outcome.elapsed()),
+            Err(_) => "-".to_string(),
+        };
+        Row {
+            day: outcome.day.to_string(),
+            title: outcome.title.to_string(),
+            part1: part1.to_string(),
+            part2: part2.to_string(),
+            time,
+        }
+    }
+}
+
+impl From<&Outcome> for Row {
+    fn from(outcome: &Outcome) -> Self {
+        let time = match outcome.parse {
+            Ok(_) => format!("{:.2?}", outcome.elapsed()),
+            Err(_) => "-".to_string(),
+        };
+        let answer = |answer: &Option<Answer>| match (&outcome.parse, answer) {
+            (Err(e), _) => format!("error: {e}"),
+            (Ok(_), Some(answer)) => match &answer.value {
+                Ok(value) => value.clone(),
+                Err(e) => format!("error: {e}"),
+            },
+            (Ok(_), None) => "-".to_string(),
+        };
+
+        Row {
+            day: outcome.day.to_string(),
+            title: outcome.title.to_string(),
+            part1: answer(&outcome.part1),
+            part2: answer(&outcome.part2),
+            time,
+        }
+    }
+}
+
+fn bench_rows(bench: &Bench) -> [[String; 6]; 3] {
+    let row = |stage: &str, stats: &Stats| {
+        [
+            bench.day.to_string(),
+            bench.title.to_string(),
+            stage.to_string(),
+            format!("{:.2?}", stats.min()),
+            format!("{:.2?}", stats.median()),
+            format!("{:.2?}", stats.mean()),
+        ]
+    };
+    [
+        row("parse", &bench.parse),
+        row("part 1", &bench.part1),
+        row("part 2", &bench.part2),
+    ]
+}
+
+impl From<Row> for [String; 5] {
+    fn from(row: Row) -> Self {
+        [row.day, row.title, row.part1, row.part2, row.time]
+    }
+}
+
+fn print_results(rows: Vec<Row>) {
+    let rows: Vec<[String; 5]> = rows.into_iter().map(Into::into).collect();
+    print_table(
+        ["Day", "Title", "Part 1", "Part 2", "Time"],
+        [true, false, false, false, true],
+        &rows,
+    );
+}
+
+/// Prints `rows` under `header`, padding every column to its widest cell.
+/// Columns flagged in `right` are right-aligned.
+fn print_table<const N: usize>(header: [&str; N], right: [bool; N], rows: &[[String; N]]) {
+    // Multi-line cells, such as parse errors with a snippet, show only their
+    // first line.
+    let first_line = |cell: &str| cell.lines().next().unwrap_or_default().to_string();
+    let rows: Vec<[String; N]> = rows
+        .iter()
+        .map(|row| row.each_ref().map(|cell| first_line(cell)))
+        .collect();
+
+    let mut widths = header.map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: [&str; N]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths.iter().zip(right))
+            .map(|(cell, (&width, right))| match right {
+                true => format!("{cell:>width$}"),
+                false => format!("{cell:<width$}"),
+            })
+            .collect();
+        println!("{}", cells.join(" | "));
+    };
+
+    print_row(header);
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|&w| "-".repeat(w))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in &rows {
+        print_row(row.each_ref().map(String::as_str));
+    }
+}
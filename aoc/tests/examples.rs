@@ -0,0 +1,48 @@
+//! Generates one test per recorded example answer in `examples/dayNN.toml`.
+
+use std::path::Path;
+
+use aoc::examples::{self, Example};
+use aoc::{Day, Part, DAYS};
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let args = Arguments::from_args();
+    let dir = Path::new(examples::EXAMPLES_DIR);
+
+    let mut trials = Vec::new();
+    for &day in DAYS {
+        let prefix = format!("day{:02}", day.number());
+        let examples = match examples::load(dir, day.number()) {
+            Ok(examples) => examples,
+            Err(e) => {
+                let message = format!("{e:?}");
+                trials.push(Trial::test(format!("{prefix}::load"), move || {
+                    Err(message.into())
+                }));
+                continue;
+            }
+        };
+
+        if examples.is_empty() {
+            trials.push(Trial::test(format!("{prefix}::has_examples"), move || {
+                Err(format!("no examples recorded for day {}", day.number()).into())
+            }));
+        }
+
+        for example in examples {
+            for part in [Part::One, Part::Two] {
+                if example.expected(part).is_some() {
+                    trials.push(trial(&prefix, day, example.clone(), part));
+                }
+            }
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
+
+fn trial(prefix: &str, day: &'static dyn Day, example: Example, part: Part) -> Trial {
+    let name = format!("{prefix}::{}::part{part}", example.name);
+    Trial::test(name, move || examples::check(day, &example, part).map_err(Failed::from))
+}
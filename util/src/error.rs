@@ -0,0 +1,130 @@
+use std::fmt::{self, Display};
+
+use crate::grid::GridError;
+use crate::point::Point;
+
+/// A puzzle input that could not be parsed, pointing at the offending line
+/// and column. Lines and columns count from 1, columns in characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    /// The full line the error points into.
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Points at byte `offset` of `input`.
+    pub fn at_offset(day: u8, input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = floor_char_boundary(input, offset.min(input.len()));
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Points at the start of `token`, which must be a slice of `input`.
+    pub fn at_token(day: u8, input: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        Self::at_offset(day, input, offset, message)
+    }
+
+    /// Points at a cell of a grid read from `input` one line per row.
+    pub fn at_point(day: u8, input: &str, point: Point, message: impl Into<String>) -> Self {
+        let line = input
+            .lines()
+            .nth(usize::try_from(point.y()).unwrap_or_default())
+            .unwrap_or_default();
+        let column = line
+            .char_indices()
+            .nth(usize::try_from(point.x()).unwrap_or_default())
+            .map_or(line.len(), |(i, _)| i);
+        Self::at_token(day, input, &line[column..], message)
+    }
+
+    pub fn from_grid(day: u8, input: &str, error: GridError) -> Self {
+        match error {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => {
+                let column = expected.min(found);
+                Self::at_point(
+                    day,
+                    input,
+                    Point::new(column as i32, row as i32),
+                    error.to_string(),
+                )
+            }
+        }
+    }
+}
+
+fn floor_char_boundary(input: &str, mut offset: usize) -> usize {
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        // Keep tabs so the caret lines up however wide the terminal draws them.
+        let pad: String = self
+            .snippet
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        write!(f, "{:gutter$} | {pad}^", "")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_token() {
+        let input = "3   4\n4   x\n";
+        let token = &input[10..11];
+        let error = ParseError::at_token(1, input, token, "expected a number");
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.snippet, "4   x");
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 2, column 5: expected a number\n2 | 4   x\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let input = "abc\nde\n";
+        let error = input.parse::<crate::Grid<char>>().unwrap_err();
+        let error = ParseError::from_grid(4, input, error);
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.snippet, "de");
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt::{self, Display};
+use std::iter::successors;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::point::Point;
+
+/// A rectangular grid of cells addressed by [`Point`], stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    Ragged {
+        /// Counted from 0, but shown counting from 1 like line numbers.
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {} has {found} cells, expected {expected}", row + 1),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "grid size does not match its cells");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Builds a grid from one line of text per row, converting each
+    /// character with `cell`. Every row must be as wide as the first.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let found = cells.len() - before;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridError::Ragged {
+                    row,
+                    expected,
+                    found,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn von_neumann_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, Point::von_neumann())
+    }
+
+    pub fn moore_neighbours(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, Point::moore())
+    }
+
+    fn neighbours(
+        &self,
+        point: Point,
+        offsets: impl Iterator<Item = Point>,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        offsets
+            .map(move |offset| point + offset)
+            .filter_map(|neighbour| self.get(neighbour).map(|cell| (neighbour, cell)))
+    }
+
+    /// Walks from `start` in steps of `step` until leaving the grid.
+    pub fn walk(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        successors(Some(start), move |&point| Some(point + step))
+            .map_while(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width as i32).map(|x| self.line(Point::new(x, 0), Point::down()))
+    }
+
+    /// Lines running down and to the right, starting from the bottom-left
+    /// corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height)
+            .rev()
+            .map(|y| Point::new(0, y))
+            .chain((1..width).map(|x| Point::new(x, 0)))
+            .map(|start| self.line(start, Point::new(1, 1)))
+    }
+
+    /// Lines running down and to the left, starting from the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..height).map(move |y| Point::new(width - 1, y)))
+            .map(|start| self.line(start, Point::new(-1, 1)))
+    }
+
+    fn line(&self, start: Point, step: Point) -> impl Iterator<Item = &T> {
+        self.walk(start, step).map(|(_, cell)| cell)
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x()).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y()).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse(input, |c| c)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn test_ragged() {
+        assert_eq!(
+            "abc\nde".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let corner: String = grid
+            .von_neumann_neighbours(Point::origin())
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(corner, "db");
+        assert_eq!(grid.moore_neighbours(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = sample();
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        let diagonals: Vec<String> = grid.diagonals().map(|d| d.collect()).collect();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+        let anti: Vec<String> = grid.anti_diagonals().map(|d| d.collect()).collect();
+        assert_eq!(anti, ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = sample();
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+}
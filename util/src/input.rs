@@ -0,0 +1,111 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the directory puzzle inputs are cached in.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory used when neither a flag nor [`INPUT_DIR_VAR`] names one.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { day: u8, path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { day, path } => write!(
+                f,
+                "no input for day {day}: {} does not exist (set {INPUT_DIR_VAR} or pass --input-dir to look elsewhere)",
+                path.display()
+            ),
+            InputError::Unreadable { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "could not read stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// The directory holding one `dayNN.txt` file per puzzle.
+#[derive(Clone, Debug)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Uses [`INPUT_DIR_VAR`] when it is set, otherwise [`DEFAULT_INPUT_DIR`].
+    pub fn from_env() -> Self {
+        Self::new(env::var_os(INPUT_DIR_VAR).unwrap_or_else(|| DEFAULT_INPUT_DIR.into()))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day:02}.txt"))
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, InputError> {
+        let path = self.path(day);
+        fs::read_to_string(&path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => InputError::Missing { day, path },
+            _ => InputError::Unreadable { path, source },
+        })
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Unreadable {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let inputs = Inputs::new("cache");
+        assert_eq!(inputs.path(5), Path::new("cache/day05.txt"));
+        assert_eq!(inputs.path(12), Path::new("cache/day12.txt"));
+    }
+
+    #[test]
+    fn test_missing_names_path() {
+        let inputs = Inputs::new("does-not-exist");
+        let error = inputs.load(3).unwrap_err();
+        assert!(matches!(error, InputError::Missing { day: 3, .. }));
+        assert!(error.to_string().contains("does-not-exist/day03.txt"));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod point;
+pub mod solution;
+
+pub use error::ParseError;
+pub use grid::Grid;
+pub use solution::Solution;
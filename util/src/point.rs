@@ -0,0 +1,220 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// A position or offset on a grid, stored as `Point(x, y)` with `y` growing
+/// downwards.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct Point(pub i32, pub i32);
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self(x, y)
+    }
+
+    pub const fn x(&self) -> i32 {
+        self.0
+    }
+
+    pub const fn y(&self) -> i32 {
+        self.1
+    }
+
+    pub fn origin() -> Self {
+        Self::new(0, 0)
+    }
+
+    pub fn up() -> Self {
+        Self::new(0, -1)
+    }
+
+    pub fn down() -> Self {
+        Self::new(0, 1)
+    }
+
+    pub fn left() -> Self {
+        Self::new(-1, 0)
+    }
+
+    pub fn right() -> Self {
+        Self::new(1, 0)
+    }
+
+    pub fn diagonal() -> impl Iterator<Item = Self> {
+        (-1..=1).flat_map(|x| {
+            (-1..=1)
+                .filter(move |&y| x != 0 && y != 0)
+                .map(move |y| Self::new(x, y))
+        })
+    }
+
+    pub fn moore() -> impl Iterator<Item = Self> {
+        (-1..=1).flat_map(|x| {
+            (-1..=1)
+                .filter(move |&y| x != 0 || y != 0)
+                .map(move |y| Self::new(x, y))
+        })
+    }
+
+    pub fn von_neumann() -> impl Iterator<Item = Self> {
+        (-1..=1).flat_map(|x| {
+            (-1..=1)
+                .filter(move |&y| (x == 0) ^ (y == 0))
+                .map(move |y| Self::new(x, y))
+        })
+    }
+
+    /// The offset that takes `self` to `other`.
+    pub fn diff(&self, other: &Point) -> Self {
+        *other - *self
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> u32 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::new(-self.0, -self.1)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+    }
+}
+
+impl Mul for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 * rhs.0, self.1 * rhs.1)
+    }
+}
+
+impl MulAssign for Point {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+        self.1 *= rhs.1;
+    }
+}
+
+impl Mul<usize> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        Self::new(self.0 * rhs as i32, self.1 * rhs as i32)
+    }
+}
+
+impl MulAssign<usize> for Point {
+    fn mul_assign(&mut self, rhs: usize) {
+        self.0 *= rhs as i32;
+        self.1 *= rhs as i32;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+    }
+}
+
+/// A byte or offset that does not name one of the four directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirectionError {
+    Byte(u8),
+    Offset(Point),
+}
+
+impl fmt::Display for DirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DirectionError::Byte(byte) => {
+                write!(f, "`{}` is not a direction", byte.escape_ascii())
+            }
+            DirectionError::Offset(point) => write!(f, "{point:?} is not a unit step"),
+        }
+    }
+}
+
+impl std::error::Error for DirectionError {}
+
+impl TryFrom<u8> for Point {
+    type Error = DirectionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'^' | b'U' => Ok(Self::up()),
+            b'v' | b'D' => Ok(Self::down()),
+            b'<' | b'L' => Ok(Self::left()),
+            b'>' | b'R' => Ok(Self::right()),
+            _ => Err(DirectionError::Byte(value)),
+        }
+    }
+}
+
+impl TryFrom<Point> for u8 {
+    type Error = DirectionError;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        match value {
+            Point(0, -1) => Ok(b'^'),
+            Point(0, 1) => Ok(b'v'),
+            Point(-1, 0) => Ok(b'<'),
+            Point(1, 0) => Ok(b'>'),
+            _ => Err(DirectionError::Offset(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbourhoods() {
+        assert_eq!(Point::von_neumann().count(), 4);
+        assert_eq!(Point::moore().count(), 8);
+        assert_eq!(Point::diagonal().count(), 4);
+        assert!(Point::von_neumann().all(|p| p.manhattan_distance(&Point::origin()) == 1));
+        assert!(Point::diagonal().all(|p| p.manhattan_distance(&Point::origin()) == 2));
+    }
+
+    #[test]
+    fn test_diff() {
+        let left = Point(1, 2);
+        let right = Point(4, 3);
+        let diff = left.diff(&right);
+        assert_eq!(diff, Point(3, 1));
+        assert_eq!(left + diff, right);
+        assert_eq!(right - diff, left);
+    }
+
+    #[test]
+    fn test_direction_bytes() {
+        for direction in Point::von_neumann() {
+            assert_eq!(u8::try_from(direction).and_then(Point::try_from), Ok(direction));
+        }
+        assert_eq!(Point::try_from(b'U').map(|p| p.opposite()), Ok(Point::down()));
+        assert_eq!(Point::try_from(b'x'), Err(DirectionError::Byte(b'x')));
+        assert_eq!(u8::try_from(Point(1, 1)), Err(DirectionError::Offset(Point(1, 1))));
+    }
+}
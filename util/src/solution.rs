@@ -0,0 +1,16 @@
+use std::fmt::Display;
+
+/// A day's puzzle, split into a parsing stage and the two parts that solve
+/// the parsed input.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+    type Error: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+    /// A part fails when the input parses but has no answer.
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error>;
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error>;
+}